@@ -0,0 +1,309 @@
+use github_client::{SimpleClient};
+use branch_protection::BranchProtector;
+use repos::Repoer;
+
+use types::{
+  BranchName,
+  GitErr,
+  OrganizationName,
+};
+
+use types::audit::{
+  RepoPolicy,
+  PolicyDeviation,
+  RepoAuditReport,
+  OrgAuditReport,
+};
+
+use types::branch_protection::BranchProtection;
+
+use types::repos::{
+  Repo,
+  Repository,
+};
+
+pub trait OrgAuditor {
+  fn audit_org(&self, org: OrganizationName, policy: RepoPolicy) -> Result<OrgAuditReport, GitErr>;
+  fn audit_repo(&self, repo: Repo, policy: &RepoPolicy) -> RepoAuditReport;
+}
+
+fn audits_protection(policy: &RepoPolicy) -> bool {
+  policy.require_protection ||
+    !policy.required_status_checks.is_empty() ||
+    policy.required_approving_review_count.is_some() ||
+    policy.enforce_admins.is_some() ||
+    policy.required_signatures.is_some()
+}
+
+fn settings_deviations(repo: &Repo, policy: &RepoPolicy) -> Vec<PolicyDeviation> {
+  let mut deviations = Vec::new();
+
+  if let Some(ref expected) = policy.default_branch {
+    if *expected != repo.default_branch {
+      deviations.push(PolicyDeviation::DefaultBranch { expected: expected.clone(), actual: repo.default_branch.clone() });
+    }
+  }
+
+  if let Some(expected) = policy.private {
+    if expected != repo.private {
+      deviations.push(PolicyDeviation::Visibility { expected_private: expected, actual_private: repo.private });
+    }
+  }
+
+  if let Some(expected) = policy.has_issues {
+    if expected != repo.has_issues {
+      deviations.push(PolicyDeviation::HasIssues { expected: expected, actual: repo.has_issues });
+    }
+  }
+
+  if let Some(expected) = policy.has_wiki {
+    if expected != repo.has_wiki {
+      deviations.push(PolicyDeviation::HasWiki { expected: expected, actual: repo.has_wiki });
+    }
+  }
+
+  deviations
+}
+
+fn protection_deviations(branch: &BranchName, protection: &BranchProtection, policy: &RepoPolicy) -> Vec<PolicyDeviation> {
+  let mut deviations = Vec::new();
+
+  let contexts = protection
+    .required_status_checks
+    .as_ref()
+    .map(|checks| checks.contexts.clone())
+    .unwrap_or(Vec::new());
+  let missing: Vec<_> = policy
+    .required_status_checks
+    .iter()
+    .filter(|context| !contexts.contains(*context))
+    .cloned()
+    .collect();
+  if !missing.is_empty() {
+    deviations.push(PolicyDeviation::MissingStatusChecks { branch: branch.clone(), missing: missing });
+  }
+
+  if let Some(expected) = policy.required_approving_review_count {
+    let actual = protection
+      .required_pull_request_reviews
+      .as_ref()
+      .and_then(|reviews| reviews.required_approving_review_count);
+    if actual.unwrap_or(0) < expected {
+      deviations.push(PolicyDeviation::ApprovingReviewCount { branch: branch.clone(), expected: expected, actual: actual });
+    }
+  }
+
+  if let Some(expected) = policy.enforce_admins {
+    let actual = protection.enforce_admins.as_ref().map(|flag| flag.enabled).unwrap_or(false);
+    if expected != actual {
+      deviations.push(PolicyDeviation::EnforceAdmins { branch: branch.clone(), expected: expected, actual: actual });
+    }
+  }
+
+  if let Some(expected) = policy.required_signatures {
+    let actual = protection.required_signatures.as_ref().map(|flag| flag.enabled).unwrap_or(false);
+    if expected != actual {
+      deviations.push(PolicyDeviation::RequiredSignatures { branch: branch.clone(), expected: expected, actual: actual });
+    }
+  }
+
+  deviations
+}
+
+fn branch_deviations<C: SimpleClient>(client: &C, repo: &Repository, branch: &BranchName, policy: &RepoPolicy) -> Result<Vec<PolicyDeviation>, GitErr> {
+  let full_branch = try!(client.get_branch(repo.clone(), branch.clone()));
+  if !full_branch.protected.unwrap_or(false) {
+    return Ok(vec![PolicyDeviation::UnprotectedBranch { branch: branch.clone() }]);
+  }
+
+  client
+    .get_branch_protection(repo.clone(), branch.clone())
+    .map(|protection| protection_deviations(branch, &protection, policy))
+}
+
+impl<C: SimpleClient> OrgAuditor for C {
+  fn audit_org(&self, org: OrganizationName, policy: RepoPolicy) -> Result<OrgAuditReport, GitErr> {
+    self
+      .list_all_org_repos(org.clone())
+      .map(|repos| {
+        let reports = repos
+          .into_iter()
+          .filter(|repo| !(policy.skip_archived && repo.archived.unwrap_or(false)))
+          .map(|repo| self.audit_repo(repo, &policy))
+          .collect();
+        OrgAuditReport { org: org, repos: reports }
+      })
+  }
+
+  // Failures to read a repository's branch are recorded in its report
+  // rather than aborting the audit of the whole organization.
+  fn audit_repo(&self, repo: Repo, policy: &RepoPolicy) -> RepoAuditReport {
+    let repository = Repository { owner: repo.owner.login.clone(), repo_name: repo.name.clone() };
+    let mut deviations = settings_deviations(&repo, policy);
+
+    if audits_protection(policy) {
+      match branch_deviations(self, &repository, &repo.default_branch, policy) {
+        Ok(branch_deviations) => deviations.extend(branch_deviations.into_iter()),
+        Err(err) => deviations.push(PolicyDeviation::Unreadable { err: err })
+      }
+    }
+
+    RepoAuditReport { repo: repository, deviations: deviations }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+  use rustc_serialize::json;
+
+  use test_client::repo_json;
+  use types::GitErr;
+  use types::audit::{
+    RepoPolicy,
+    PolicyDeviation,
+    RepoAuditReport,
+  };
+  use types::branch_protection::BranchProtection;
+  use types::repos::{
+    Repo,
+    Repository,
+  };
+
+  use super::{
+    audits_protection,
+    settings_deviations,
+    protection_deviations,
+  };
+
+  fn policy() -> RepoPolicy {
+    RepoPolicy {
+      default_branch: None,
+      private: None,
+      has_issues: None,
+      has_wiki: None,
+      require_protection: false,
+      required_status_checks: Vec::new(),
+      required_approving_review_count: None,
+      enforce_admins: None,
+      required_signatures: None,
+      skip_archived: false
+    }
+  }
+
+  // A repository on master that is public, with issues and without a wiki
+  fn repo() -> Repo {
+    json::decode(&repo_json("test_owner", "test_repo")).unwrap()
+  }
+
+  // Requires the "ci" check and one approval, without admins or signatures
+  fn protection() -> BranchProtection {
+    json::decode(r#"{
+      "required_status_checks": { "strict": true, "contexts": ["ci"] },
+      "enforce_admins": { "enabled": false },
+      "required_pull_request_reviews": {
+        "dismiss_stale_reviews": false,
+        "require_code_owner_reviews": false,
+        "required_approving_review_count": 1
+      },
+      "required_signatures": { "enabled": false }
+    }"#).unwrap()
+  }
+
+  fn master() -> String {
+    "master".to_owned()
+  }
+
+  #[test]
+  fn it_audits_protection_only_when_a_protection_setting_is_set() {
+    let cases: Vec<(RepoPolicy, bool)> = vec![
+      (policy(), false),
+      (RepoPolicy { default_branch: Some(master()), private: Some(true), ..policy() }, false),
+      (RepoPolicy { require_protection: true, ..policy() }, true),
+      (RepoPolicy { required_status_checks: vec!["ci".to_owned()], ..policy() }, true),
+      (RepoPolicy { required_approving_review_count: Some(1), ..policy() }, true),
+      (RepoPolicy { enforce_admins: Some(false), ..policy() }, true),
+      (RepoPolicy { required_signatures: Some(false), ..policy() }, true),
+    ];
+
+    for (policy, expected) in cases {
+      expect!(audits_protection(&policy)).to(be_equal_to(expected));
+    }
+  }
+
+  #[test]
+  fn it_finds_settings_deviations() {
+    let cases: Vec<(RepoPolicy, Vec<PolicyDeviation>)> = vec![
+      (policy(), vec![]),
+      (RepoPolicy { default_branch: Some(master()), private: Some(false), has_issues: Some(true), has_wiki: Some(false), ..policy() }, vec![]),
+      (RepoPolicy { default_branch: Some("main".to_owned()), ..policy() },
+       vec![PolicyDeviation::DefaultBranch { expected: "main".to_owned(), actual: master() }]),
+      (RepoPolicy { private: Some(true), ..policy() },
+       vec![PolicyDeviation::Visibility { expected_private: true, actual_private: false }]),
+      (RepoPolicy { has_issues: Some(false), ..policy() },
+       vec![PolicyDeviation::HasIssues { expected: false, actual: true }]),
+      (RepoPolicy { has_wiki: Some(true), ..policy() },
+       vec![PolicyDeviation::HasWiki { expected: true, actual: false }]),
+    ];
+
+    let repo = repo();
+    for (policy, expected) in cases {
+      expect!(settings_deviations(&repo, &policy)).to(be_equal_to(expected));
+    }
+  }
+
+  #[test]
+  fn it_finds_protection_deviations() {
+    let cases: Vec<(RepoPolicy, Vec<PolicyDeviation>)> = vec![
+      (RepoPolicy { require_protection: true, ..policy() }, vec![]),
+      (RepoPolicy { required_status_checks: vec!["ci".to_owned()], required_approving_review_count: Some(1), enforce_admins: Some(false), required_signatures: Some(false), ..policy() }, vec![]),
+      (RepoPolicy { required_status_checks: vec!["ci".to_owned(), "lint".to_owned()], ..policy() },
+       vec![PolicyDeviation::MissingStatusChecks { branch: master(), missing: vec!["lint".to_owned()] }]),
+      (RepoPolicy { required_approving_review_count: Some(2), ..policy() },
+       vec![PolicyDeviation::ApprovingReviewCount { branch: master(), expected: 2, actual: Some(1) }]),
+      (RepoPolicy { enforce_admins: Some(true), ..policy() },
+       vec![PolicyDeviation::EnforceAdmins { branch: master(), expected: true, actual: false }]),
+      (RepoPolicy { required_signatures: Some(true), ..policy() },
+       vec![PolicyDeviation::RequiredSignatures { branch: master(), expected: true, actual: false }]),
+    ];
+
+    let protection = protection();
+    for (policy, expected) in cases {
+      expect!(protection_deviations(&master(), &protection, &policy)).to(be_equal_to(expected));
+    }
+  }
+
+  #[test]
+  fn it_treats_missing_protection_settings_as_disabled() {
+    let protection: BranchProtection = json::decode("{}").unwrap();
+    let policy = RepoPolicy { required_status_checks: vec!["ci".to_owned()], required_approving_review_count: Some(1), enforce_admins: Some(true), ..policy() };
+    let expected = vec![
+      PolicyDeviation::MissingStatusChecks { branch: master(), missing: vec!["ci".to_owned()] },
+      PolicyDeviation::ApprovingReviewCount { branch: master(), expected: 1, actual: None },
+      PolicyDeviation::EnforceAdmins { branch: master(), expected: true, actual: false },
+    ];
+    expect!(protection_deviations(&master(), &protection, &policy)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_encodes_unreadable_errors_as_their_message() {
+    let report = RepoAuditReport {
+      repo: Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() },
+      deviations: vec![PolicyDeviation::Unreadable { err: GitErr::NetworkErr("reset".to_owned()) }]
+    };
+    let expected = r#"{"repo":{"owner":"test_owner","repo_name":"test_repo"},"deviations":[{"kind":"unreadable","err":"Network error: reset"}]}"#;
+    expect!(json::encode(&report).unwrap()).to(be_equal_to(expected.to_owned()));
+  }
+
+  #[test]
+  fn it_encodes_deviations_with_named_fields() {
+    let missing = PolicyDeviation::MissingStatusChecks { branch: master(), missing: vec!["ci".to_owned()] };
+    let expected = r#"{"kind":"missing_status_checks","branch":"master","missing":["ci"]}"#;
+    expect!(json::encode(&missing).unwrap()).to(be_equal_to(expected.to_owned()));
+
+    let reviews = PolicyDeviation::ApprovingReviewCount { branch: master(), expected: 2, actual: None };
+    let expected = r#"{"kind":"approving_review_count","branch":"master","expected":2,"actual":null}"#;
+    expect!(json::encode(&reviews).unwrap()).to(be_equal_to(expected.to_owned()));
+  }
+}
@@ -0,0 +1,67 @@
+pub mod url_builders;
+
+use hyper::method::Method;
+
+use github_client::{
+  SimpleClient,
+  deleted_status,
+};
+
+use types::{
+  BranchName,
+  GitErr,
+  DeletedStatus,
+};
+
+use types::branch_protection::{
+  BranchProtection,
+  UpdateBranchProtection,
+  ProtectionFlag,
+};
+
+use types::repos::Repository;
+
+pub trait BranchProtector {
+  fn get_branch_protection(&self, repo: Repository, branch: BranchName) -> Result<BranchProtection, GitErr>;
+  fn update_branch_protection(&self, repo: Repository, branch: BranchName, details: UpdateBranchProtection) -> Result<BranchProtection, GitErr>;
+  fn delete_branch_protection(&self, repo: Repository, branch: BranchName) -> Result<DeletedStatus, GitErr>;
+  fn get_required_signatures(&self, repo: Repository, branch: BranchName) -> Result<ProtectionFlag, GitErr>;
+  fn enable_required_signatures(&self, repo: Repository, branch: BranchName) -> Result<ProtectionFlag, GitErr>;
+  fn disable_required_signatures(&self, repo: Repository, branch: BranchName) -> Result<DeletedStatus, GitErr>;
+}
+
+impl<C: SimpleClient> BranchProtector for C {
+  fn get_branch_protection(&self, repo: Repository, branch: BranchName) -> Result<BranchProtection, GitErr> {
+    let url = url_builders::branch_protection_at(&repo, &branch);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn update_branch_protection(&self, repo: Repository, branch: BranchName, details: UpdateBranchProtection) -> Result<BranchProtection, GitErr> {
+    let url = url_builders::branch_protection_at(&repo, &branch);
+    self.request_with_payload(Method::Put, url, details)
+  }
+
+  fn delete_branch_protection(&self, repo: Repository, branch: BranchName) -> Result<DeletedStatus, GitErr> {
+    let url = url_builders::branch_protection_at(&repo, &branch);
+    self
+      .request_for_status(Method::Delete, url)
+      .and_then(deleted_status)
+  }
+
+  fn get_required_signatures(&self, repo: Repository, branch: BranchName) -> Result<ProtectionFlag, GitErr> {
+    let url = url_builders::required_signatures_at(&repo, &branch);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn enable_required_signatures(&self, repo: Repository, branch: BranchName) -> Result<ProtectionFlag, GitErr> {
+    let url = url_builders::required_signatures_at(&repo, &branch);
+    self.request_without_payload(Method::Post, url)
+  }
+
+  fn disable_required_signatures(&self, repo: Repository, branch: BranchName) -> Result<DeletedStatus, GitErr> {
+    let url = url_builders::required_signatures_at(&repo, &branch);
+    self
+      .request_for_status(Method::Delete, url)
+      .and_then(deleted_status)
+  }
+}
@@ -0,0 +1,42 @@
+use types::{
+  Url,
+  BranchName,
+};
+use types::repos::Repository;
+
+use repos::url_builders::branch_at;
+
+pub fn branch_protection_at(repo: &Repository, branch: &BranchName) -> Url {
+  branch_at(repo, branch) + "/protection"
+}
+
+pub fn required_signatures_at(repo: &Repository, branch: &BranchName) -> Url {
+  branch_protection_at(repo, branch) + "/required_signatures"
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+
+  use types::repos::Repository;
+
+  use super::{
+    branch_protection_at,
+    required_signatures_at,
+  };
+
+  #[test]
+  fn it_builds_branch_protection_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/branches/test_branch/protection";
+    expect!(branch_protection_at(&repo, &("test_branch".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_required_signatures_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/branches/test_branch/protection/required_signatures";
+    expect!(required_signatures_at(&repo, &("test_branch".to_owned()))).to(be_equal_to(expected));
+  }
+}
@@ -0,0 +1,271 @@
+pub mod url_builders;
+
+use hyper::method::Method;
+use hyper::status::StatusCode;
+
+use github_client::{SimpleClient};
+
+use types::{
+  GitErr,
+  GitRef,
+};
+
+use types::checks::{
+  CheckRunId,
+  CheckSuiteId,
+  CheckRun,
+  CheckRunList,
+  CheckRunsQuery,
+  CheckRunOutput,
+  CreateCheckRun,
+  UpdateCheckRun,
+  CheckAnnotation,
+  CheckSuite,
+  CheckSuiteList,
+  CreateCheckSuite,
+  CheckSuitePreferences,
+  CheckSuitePreferencesResult,
+  RerequestStatus,
+};
+
+use types::repos::Repository;
+
+// Github rejects check run outputs with more annotations than this
+const MAX_ANNOTATIONS_PER_REQUEST: usize = 50;
+
+pub trait Checker {
+  fn create_check_run(&self, repo: Repository, details: CreateCheckRun) -> Result<CheckRun, GitErr>;
+  fn update_check_run(&self, repo: Repository, check_run_id: CheckRunId, details: UpdateCheckRun) -> Result<CheckRun, GitErr>;
+  fn get_check_run(&self, repo: Repository, check_run_id: CheckRunId) -> Result<CheckRun, GitErr>;
+  fn list_check_runs_for_ref(&self, repo: Repository, git_ref: GitRef, query: Option<CheckRunsQuery>) -> Result<CheckRunList, GitErr>;
+  fn list_check_runs_in_suite(&self, repo: Repository, check_suite_id: CheckSuiteId, query: Option<CheckRunsQuery>) -> Result<CheckRunList, GitErr>;
+  fn list_check_run_annotations(&self, repo: Repository, check_run_id: CheckRunId) -> Result<Vec<CheckAnnotation>, GitErr>;
+  fn rerequest_check_run(&self, repo: Repository, check_run_id: CheckRunId) -> Result<RerequestStatus, GitErr>;
+  fn create_check_suite(&self, repo: Repository, details: CreateCheckSuite) -> Result<CheckSuite, GitErr>;
+  fn get_check_suite(&self, repo: Repository, check_suite_id: CheckSuiteId) -> Result<CheckSuite, GitErr>;
+  fn list_check_suites_for_ref(&self, repo: Repository, git_ref: GitRef) -> Result<CheckSuiteList, GitErr>;
+  fn set_check_suite_preferences(&self, repo: Repository, preferences: CheckSuitePreferences) -> Result<CheckSuitePreferencesResult, GitErr>;
+  fn rerequest_check_suite(&self, repo: Repository, check_suite_id: CheckSuiteId) -> Result<RerequestStatus, GitErr>;
+}
+
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+struct AppendCheckRunOutput {
+  output: CheckRunOutput
+}
+
+// Splits an output into the one sent with the initial request and the
+// follow-up outputs carrying the remaining annotations.
+fn batch_output(output: Option<CheckRunOutput>) -> (Option<CheckRunOutput>, Vec<CheckRunOutput>) {
+  match output {
+    None => (None, Vec::new()),
+    Some(output) => {
+      let mut batches: Vec<CheckRunOutput> =
+        output
+          .annotations
+          .chunks(MAX_ANNOTATIONS_PER_REQUEST)
+          .map(|annotations| CheckRunOutput {
+            title: output.title.clone(),
+            summary: output.summary.clone(),
+            text: output.text.clone(),
+            annotations: annotations.to_vec(),
+            images: Vec::new()
+          })
+          .collect();
+
+      if batches.is_empty() {
+        return (Some(output), Vec::new());
+      }
+
+      let rest = batches.split_off(1);
+      let mut first = batches.remove(0);
+      first.images = output.images.clone();
+      (Some(first), rest)
+    }
+  }
+}
+
+fn append_outputs<C: SimpleClient>(client: &C, repo: &Repository, check_run: CheckRun, outputs: Vec<CheckRunOutput>) -> Result<CheckRun, GitErr> {
+  outputs
+    .into_iter()
+    .fold(Ok(check_run), |result, output| {
+      result.and_then(|check_run| {
+        let url = url_builders::check_run_at(repo, &check_run.id);
+        client.request_with_payload(Method::Patch, url, AppendCheckRunOutput { output: output })
+      })
+    })
+}
+
+fn rerequest_status(status: StatusCode) -> Result<RerequestStatus, GitErr> {
+  match status {
+    StatusCode::Created => Ok(RerequestStatus::Rerequested),
+    StatusCode::Forbidden | StatusCode::NotFound => Ok(RerequestStatus::NotRerequested),
+    status => Err(GitErr::UnexpectedStatus(status.to_string()))
+  }
+}
+
+impl<C: SimpleClient> Checker for C {
+  fn create_check_run(&self, repo: Repository, details: CreateCheckRun) -> Result<CheckRun, GitErr> {
+    let url = url_builders::check_runs(&repo);
+    let mut details = details;
+    let (output, remaining_outputs) = batch_output(details.output);
+    details.output = output;
+    self
+      .request_with_payload(Method::Post, url, details)
+      .and_then(|check_run| append_outputs(self, &repo, check_run, remaining_outputs))
+  }
+
+  fn update_check_run(&self, repo: Repository, check_run_id: CheckRunId, details: UpdateCheckRun) -> Result<CheckRun, GitErr> {
+    let url = url_builders::check_run_at(&repo, &check_run_id);
+    let mut details = details;
+    let (output, remaining_outputs) = batch_output(details.output);
+    details.output = output;
+    self
+      .request_with_payload(Method::Patch, url, details)
+      .and_then(|check_run| append_outputs(self, &repo, check_run, remaining_outputs))
+  }
+
+  fn get_check_run(&self, repo: Repository, check_run_id: CheckRunId) -> Result<CheckRun, GitErr> {
+    let url = url_builders::check_run_at(&repo, &check_run_id);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn list_check_runs_for_ref(&self, repo: Repository, git_ref: GitRef, query: Option<CheckRunsQuery>) -> Result<CheckRunList, GitErr> {
+    let url = url_builders::commit_check_runs(&repo, &git_ref);
+    match query {
+      Some(query) => self.request_with_query(Method::Get, url, query),
+      None => self.request_without_payload(Method::Get, url)
+    }
+  }
+
+  fn list_check_runs_in_suite(&self, repo: Repository, check_suite_id: CheckSuiteId, query: Option<CheckRunsQuery>) -> Result<CheckRunList, GitErr> {
+    let url = url_builders::check_suite_check_runs(&repo, &check_suite_id);
+    match query {
+      Some(query) => self.request_with_query(Method::Get, url, query),
+      None => self.request_without_payload(Method::Get, url)
+    }
+  }
+
+  fn list_check_run_annotations(&self, repo: Repository, check_run_id: CheckRunId) -> Result<Vec<CheckAnnotation>, GitErr> {
+    let url = url_builders::check_run_annotations(&repo, &check_run_id);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn rerequest_check_run(&self, repo: Repository, check_run_id: CheckRunId) -> Result<RerequestStatus, GitErr> {
+    let url = url_builders::check_run_rerequest(&repo, &check_run_id);
+    self
+      .request_for_status(Method::Post, url)
+      .and_then(rerequest_status)
+  }
+
+  fn create_check_suite(&self, repo: Repository, details: CreateCheckSuite) -> Result<CheckSuite, GitErr> {
+    let url = url_builders::check_suites(&repo);
+    self.request_with_payload(Method::Post, url, details)
+  }
+
+  fn get_check_suite(&self, repo: Repository, check_suite_id: CheckSuiteId) -> Result<CheckSuite, GitErr> {
+    let url = url_builders::check_suite_at(&repo, &check_suite_id);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn list_check_suites_for_ref(&self, repo: Repository, git_ref: GitRef) -> Result<CheckSuiteList, GitErr> {
+    let url = url_builders::commit_check_suites(&repo, &git_ref);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn set_check_suite_preferences(&self, repo: Repository, preferences: CheckSuitePreferences) -> Result<CheckSuitePreferencesResult, GitErr> {
+    let url = url_builders::check_suite_preferences(&repo);
+    self.request_with_payload(Method::Patch, url, preferences)
+  }
+
+  fn rerequest_check_suite(&self, repo: Repository, check_suite_id: CheckSuiteId) -> Result<RerequestStatus, GitErr> {
+    let url = url_builders::check_suite_rerequest(&repo, &check_suite_id);
+    self
+      .request_for_status(Method::Post, url)
+      .and_then(rerequest_status)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+
+  use types::checks::{
+    AnnotationLevel,
+    CheckAnnotation,
+    CheckImage,
+    CheckRunOutput,
+  };
+
+  use super::batch_output;
+
+  fn output(annotation_count: u32) -> CheckRunOutput {
+    CheckRunOutput {
+      title: "Lint".to_owned(),
+      summary: "Lint results".to_owned(),
+      text: None,
+      annotations: (0..annotation_count).map(|line| CheckAnnotation {
+        path: "src/lib.rs".to_owned(),
+        start_line: line,
+        end_line: line,
+        start_column: None,
+        end_column: None,
+        annotation_level: AnnotationLevel::Warning,
+        message: "unused import".to_owned(),
+        title: None,
+        raw_details: None,
+        blob_href: None
+      }).collect(),
+      images: vec![CheckImage { alt: "graph".to_owned(), image_url: "https://example.com/graph.png".to_owned(), caption: None }]
+    }
+  }
+
+  fn batch_sizes(annotation_count: u32) -> (usize, Vec<usize>) {
+    let (first, rest) = batch_output(Some(output(annotation_count)));
+    (first.unwrap().annotations.len(), rest.iter().map(|output| output.annotations.len()).collect())
+  }
+
+  #[test]
+  fn it_sends_nothing_without_an_output() {
+    let (first, rest) = batch_output(None);
+    expect!(first).to(be_equal_to(None));
+    expect!(rest.len()).to(be_equal_to(0));
+  }
+
+  #[test]
+  fn it_sends_an_output_without_annotations_as_is() {
+    let (first, rest) = batch_output(Some(output(0)));
+    expect!(first).to(be_equal_to(Some(output(0))));
+    expect!(rest.len()).to(be_equal_to(0));
+  }
+
+  #[test]
+  fn it_sends_up_to_fifty_annotations_at_once() {
+    expect!(batch_sizes(50)).to(be_equal_to((50, vec![])));
+  }
+
+  #[test]
+  fn it_splits_off_annotations_past_fifty() {
+    expect!(batch_sizes(51)).to(be_equal_to((50, vec![1])));
+  }
+
+  #[test]
+  fn it_splits_many_annotations_into_batches_of_fifty() {
+    expect!(batch_sizes(120)).to(be_equal_to((50, vec![50, 20])));
+  }
+
+  #[test]
+  fn it_keeps_annotations_in_order() {
+    let (first, rest) = batch_output(Some(output(120)));
+    expect!(first.unwrap().annotations[0].start_line).to(be_equal_to(0));
+    expect!(rest[0].annotations[0].start_line).to(be_equal_to(50));
+    expect!(rest[1].annotations[19].start_line).to(be_equal_to(119));
+  }
+
+  #[test]
+  fn it_sends_images_only_with_the_first_batch() {
+    let (first, rest) = batch_output(Some(output(120)));
+    expect!(first.unwrap().images.len()).to(be_equal_to(1));
+    expect!(rest.iter().all(|output| output.images.is_empty())).to(be_equal_to(true));
+  }
+}
@@ -0,0 +1,155 @@
+use types::{
+  Url,
+  GitRef,
+};
+use types::checks::{
+  CheckRunId,
+  CheckSuiteId,
+};
+use types::repos::Repository;
+
+use repos::url_builders::repo_at;
+use commits::url_builders::commit_at;
+
+pub fn check_runs(repo: &Repository) -> Url {
+  repo_at(repo) + "/check-runs"
+}
+
+pub fn check_run_at(repo: &Repository, check_run_id: &CheckRunId) -> Url {
+  check_runs(repo) + "/" + &check_run_id.to_string()
+}
+
+pub fn check_run_annotations(repo: &Repository, check_run_id: &CheckRunId) -> Url {
+  check_run_at(repo, check_run_id) + "/annotations"
+}
+
+pub fn check_run_rerequest(repo: &Repository, check_run_id: &CheckRunId) -> Url {
+  check_run_at(repo, check_run_id) + "/rerequest"
+}
+
+pub fn commit_check_runs(repo: &Repository, git_ref: &GitRef) -> Url {
+  commit_at(repo, git_ref) + "/check-runs"
+}
+
+pub fn check_suites(repo: &Repository) -> Url {
+  repo_at(repo) + "/check-suites"
+}
+
+pub fn check_suite_at(repo: &Repository, check_suite_id: &CheckSuiteId) -> Url {
+  check_suites(repo) + "/" + &check_suite_id.to_string()
+}
+
+pub fn check_suite_check_runs(repo: &Repository, check_suite_id: &CheckSuiteId) -> Url {
+  check_suite_at(repo, check_suite_id) + "/check-runs"
+}
+
+pub fn check_suite_rerequest(repo: &Repository, check_suite_id: &CheckSuiteId) -> Url {
+  check_suite_at(repo, check_suite_id) + "/rerequest"
+}
+
+pub fn check_suite_preferences(repo: &Repository) -> Url {
+  check_suites(repo) + "/preferences"
+}
+
+pub fn commit_check_suites(repo: &Repository, git_ref: &GitRef) -> Url {
+  commit_at(repo, git_ref) + "/check-suites"
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+
+  use types::repos::Repository;
+
+  use super::{
+    check_runs,
+    check_run_at,
+    check_run_annotations,
+    check_run_rerequest,
+    commit_check_runs,
+    check_suites,
+    check_suite_at,
+    check_suite_check_runs,
+    check_suite_rerequest,
+    check_suite_preferences,
+    commit_check_suites,
+  };
+
+  #[test]
+  fn it_builds_check_runs() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/check-runs";
+    expect!(check_runs(&repo)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_check_run_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/check-runs/4";
+    expect!(check_run_at(&repo, &4)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_check_run_annotations() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/check-runs/4/annotations";
+    expect!(check_run_annotations(&repo, &4)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_check_run_rerequest() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/check-runs/4/rerequest";
+    expect!(check_run_rerequest(&repo, &4)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_commit_check_runs() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/commits/master/check-runs";
+    expect!(commit_check_runs(&repo, &("master".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_check_suites() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/check-suites";
+    expect!(check_suites(&repo)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_check_suite_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/check-suites/5";
+    expect!(check_suite_at(&repo, &5)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_check_suite_check_runs() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/check-suites/5/check-runs";
+    expect!(check_suite_check_runs(&repo, &5)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_check_suite_rerequest() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/check-suites/5/rerequest";
+    expect!(check_suite_rerequest(&repo, &5)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_check_suite_preferences() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/check-suites/preferences";
+    expect!(check_suite_preferences(&repo)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_commit_check_suites() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/commits/master/check-suites";
+    expect!(commit_check_suites(&repo, &("master".to_owned()))).to(be_equal_to(expected));
+  }
+}
@@ -0,0 +1,120 @@
+pub mod url_builders;
+
+use hyper::method::Method;
+use hyper::status::StatusCode;
+
+use github_client::{
+  SimpleClient,
+  deleted_status,
+};
+
+use types::{
+  GitErr,
+  DeletedStatus,
+};
+
+use types::repos::{
+  Repository,
+  InvitationId,
+  CollaboratorsQuery,
+  RepoCollaborator,
+  CollaboratorPermission,
+  CollaboratorStatus,
+  AddCollaborator,
+  AddedCollaboratorStatus,
+  RepoInvitation,
+  AcceptedStatus,
+};
+use types::users::UserName;
+
+pub trait Collaborator {
+  fn list_collaborators(&self, repo: Repository, query: Option<CollaboratorsQuery>) -> Result<Vec<RepoCollaborator>, GitErr>;
+  fn is_collaborator(&self, repo: Repository, user: UserName) -> Result<CollaboratorStatus, GitErr>;
+  fn get_collaborator_permission(&self, repo: Repository, user: UserName) -> Result<CollaboratorPermission, GitErr>;
+  fn add_collaborator(&self, repo: Repository, user: UserName, details: AddCollaborator) -> Result<AddedCollaboratorStatus, GitErr>;
+  fn remove_collaborator(&self, repo: Repository, user: UserName) -> Result<DeletedStatus, GitErr>;
+  fn list_repo_invitations(&self, repo: Repository) -> Result<Vec<RepoInvitation>, GitErr>;
+  fn delete_repo_invitation(&self, repo: Repository, invitation_id: InvitationId) -> Result<DeletedStatus, GitErr>;
+  fn list_own_invitations(&self) -> Result<Vec<RepoInvitation>, GitErr>;
+  fn accept_invitation(&self, invitation_id: InvitationId) -> Result<AcceptedStatus, GitErr>;
+  fn decline_invitation(&self, invitation_id: InvitationId) -> Result<DeletedStatus, GitErr>;
+}
+
+impl<C: SimpleClient> Collaborator for C {
+  fn list_collaborators(&self, repo: Repository, query: Option<CollaboratorsQuery>) -> Result<Vec<RepoCollaborator>, GitErr> {
+    let url = url_builders::collaborators_at(&repo);
+    match query {
+      Some(query) => self.request_with_query(Method::Get, url, query),
+      None => self.request_without_payload(Method::Get, url)
+    }
+  }
+
+  fn is_collaborator(&self, repo: Repository, user: UserName) -> Result<CollaboratorStatus, GitErr> {
+    let url = url_builders::collaborator_at(&repo, &user);
+    self
+      .request_for_status(Method::Get, url)
+      .and_then(|status| match status {
+        StatusCode::NoContent => Ok(CollaboratorStatus::Collaborator),
+        StatusCode::NotFound => Ok(CollaboratorStatus::NotCollaborator),
+        status => Err(GitErr::UnexpectedStatus(status.to_string()))
+      })
+  }
+
+  fn get_collaborator_permission(&self, repo: Repository, user: UserName) -> Result<CollaboratorPermission, GitErr> {
+    let url = url_builders::collaborator_permission_at(&repo, &user);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn add_collaborator(&self, repo: Repository, user: UserName, details: AddCollaborator) -> Result<AddedCollaboratorStatus, GitErr> {
+    let url = url_builders::collaborator_at(&repo, &user);
+    self
+      .request_with_payload_for_status(Method::Put, url, details)
+      .and_then(|status| match status {
+        StatusCode::Created => Ok(AddedCollaboratorStatus::Invited),
+        StatusCode::NoContent => Ok(AddedCollaboratorStatus::AlreadyCollaborator),
+        status => Err(GitErr::UnexpectedStatus(status.to_string()))
+      })
+  }
+
+  fn remove_collaborator(&self, repo: Repository, user: UserName) -> Result<DeletedStatus, GitErr> {
+    let url = url_builders::collaborator_at(&repo, &user);
+    self
+      .request_for_status(Method::Delete, url)
+      .and_then(deleted_status)
+  }
+
+  fn list_repo_invitations(&self, repo: Repository) -> Result<Vec<RepoInvitation>, GitErr> {
+    let url = url_builders::repo_invitations(&repo);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn delete_repo_invitation(&self, repo: Repository, invitation_id: InvitationId) -> Result<DeletedStatus, GitErr> {
+    let url = url_builders::repo_invitation_at(&repo, &invitation_id);
+    self
+      .request_for_status(Method::Delete, url)
+      .and_then(deleted_status)
+  }
+
+  fn list_own_invitations(&self) -> Result<Vec<RepoInvitation>, GitErr> {
+    let url = url_builders::own_invitations();
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn accept_invitation(&self, invitation_id: InvitationId) -> Result<AcceptedStatus, GitErr> {
+    let url = url_builders::own_invitation_at(&invitation_id);
+    self
+      .request_for_status(Method::Patch, url)
+      .and_then(|status| match status {
+        StatusCode::NoContent => Ok(AcceptedStatus::Accepted),
+        StatusCode::Forbidden | StatusCode::NotFound => Ok(AcceptedStatus::NotAccepted),
+        status => Err(GitErr::UnexpectedStatus(status.to_string()))
+      })
+  }
+
+  fn decline_invitation(&self, invitation_id: InvitationId) -> Result<DeletedStatus, GitErr> {
+    let url = url_builders::own_invitation_at(&invitation_id);
+    self
+      .request_for_status(Method::Delete, url)
+      .and_then(deleted_status)
+  }
+}
@@ -0,0 +1,101 @@
+use types::Url;
+use types::users::UserName;
+use types::repos::{
+  Repository,
+  InvitationId,
+};
+
+use repos::url_builders::repo_at;
+
+pub fn collaborators_at(repo: &Repository) -> Url {
+  repo_at(repo) + "/collaborators"
+}
+
+pub fn collaborator_at(repo: &Repository, user: &UserName) -> Url {
+  collaborators_at(repo) + "/" + user
+}
+
+pub fn collaborator_permission_at(repo: &Repository, user: &UserName) -> Url {
+  collaborator_at(repo, user) + "/permission"
+}
+
+pub fn repo_invitations(repo: &Repository) -> Url {
+  repo_at(repo) + "/invitations"
+}
+
+pub fn repo_invitation_at(repo: &Repository, invitation_id: &InvitationId) -> Url {
+  repo_invitations(repo) + "/" + &invitation_id.to_string()
+}
+
+pub fn own_invitations() -> Url {
+  "https://api.github.com/user/repository_invitations".to_owned()
+}
+
+pub fn own_invitation_at(invitation_id: &InvitationId) -> Url {
+  own_invitations() + "/" + &invitation_id.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+
+  use types::repos::Repository;
+
+  use super::{
+    collaborators_at,
+    collaborator_at,
+    collaborator_permission_at,
+    repo_invitations,
+    repo_invitation_at,
+    own_invitations,
+    own_invitation_at,
+  };
+
+  #[test]
+  fn it_builds_collaborators_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/collaborators";
+    expect!(collaborators_at(&repo)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_collaborator_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/collaborators/test_user";
+    expect!(collaborator_at(&repo, &("test_user".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_collaborator_permission_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/collaborators/test_user/permission";
+    expect!(collaborator_permission_at(&repo, &("test_user".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_repo_invitations() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/invitations";
+    expect!(repo_invitations(&repo)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_repo_invitation_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/invitations/7";
+    expect!(repo_invitation_at(&repo, &7)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_own_invitations() {
+    let expected = "https://api.github.com/user/repository_invitations";
+    expect!(own_invitations()).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_own_invitation_at() {
+    let expected = "https://api.github.com/user/repository_invitations/7";
+    expect!(own_invitation_at(&7)).to(be_equal_to(expected));
+  }
+}
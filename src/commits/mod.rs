@@ -0,0 +1,45 @@
+pub mod url_builders;
+
+use hyper::method::Method;
+
+use github_client::{SimpleClient};
+
+use types::{
+  GitErr,
+  GitRef,
+};
+
+use types::commits::{
+  CommitsQuery,
+  RepoCommit,
+  FullCommit,
+  Comparison,
+};
+
+use types::repos::Repository;
+
+pub trait Committer {
+  fn list_repo_commits(&self, repo: Repository, query: Option<CommitsQuery>) -> Result<Vec<RepoCommit>, GitErr>;
+  fn get_commit(&self, repo: Repository, git_ref: GitRef) -> Result<FullCommit, GitErr>;
+  fn compare_commits(&self, repo: Repository, base: GitRef, head: GitRef) -> Result<Comparison, GitErr>;
+}
+
+impl<C: SimpleClient> Committer for C {
+  fn list_repo_commits(&self, repo: Repository, query: Option<CommitsQuery>) -> Result<Vec<RepoCommit>, GitErr> {
+    let url = url_builders::commits(&repo);
+    match query {
+      Some(query) => self.request_with_query(Method::Get, url, query),
+      None => self.request_without_payload(Method::Get, url)
+    }
+  }
+
+  fn get_commit(&self, repo: Repository, git_ref: GitRef) -> Result<FullCommit, GitErr> {
+    let url = url_builders::commit_at(&repo, &git_ref);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn compare_commits(&self, repo: Repository, base: GitRef, head: GitRef) -> Result<Comparison, GitErr> {
+    let url = url_builders::compare(&repo, &base, &head);
+    self.request_without_payload(Method::Get, url)
+  }
+}
@@ -0,0 +1,54 @@
+use types::{
+  Url,
+  GitRef,
+};
+use types::repos::Repository;
+
+use repos::url_builders::repo_at;
+
+pub fn commits(repo: &Repository) -> Url {
+  repo_at(repo) + "/commits"
+}
+
+pub fn commit_at(repo: &Repository, git_ref: &GitRef) -> Url {
+  commits(repo) + "/" + git_ref
+}
+
+pub fn compare(repo: &Repository, base: &GitRef, head: &GitRef) -> Url {
+  repo_at(repo) + "/compare/" + base + "..." + head
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+
+  use types::repos::Repository;
+
+  use super::{
+    commits,
+    commit_at,
+    compare,
+  };
+
+  #[test]
+  fn it_builds_commits() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/commits";
+    expect!(commits(&repo)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_commit_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/commits/abc123";
+    expect!(commit_at(&repo, &("abc123".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_compare() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/compare/v1.0.0...master";
+    expect!(compare(&repo, &("v1.0.0".to_owned()), &("master".to_owned()))).to(be_equal_to(expected));
+  }
+}
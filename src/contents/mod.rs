@@ -0,0 +1,80 @@
+pub mod url_builders;
+
+use hyper::method::Method;
+
+use github_client::{SimpleClient};
+
+use types::{
+  GitErr,
+  GitRef,
+  Filename,
+};
+
+use types::contents::{
+  Content,
+  ContentsQuery,
+  CreateFile,
+  UpdateFile,
+  DeleteFile,
+  FileCommit,
+};
+
+use types::repos::Repository;
+
+pub trait Contenter {
+  fn get_contents(&self, repo: Repository, path: Filename, query: Option<ContentsQuery>) -> Result<Content, GitErr>;
+  fn list_directory(&self, repo: Repository, path: Filename, query: Option<ContentsQuery>) -> Result<Vec<Content>, GitErr>;
+  fn get_readme(&self, repo: Repository, query: Option<ContentsQuery>) -> Result<Content, GitErr>;
+  fn create_file(&self, repo: Repository, path: Filename, details: CreateFile) -> Result<FileCommit, GitErr>;
+  fn update_file(&self, repo: Repository, path: Filename, details: UpdateFile) -> Result<FileCommit, GitErr>;
+  fn delete_file(&self, repo: Repository, path: Filename, details: DeleteFile) -> Result<FileCommit, GitErr>;
+  fn get_file_bytes(&self, repo: Repository, path: Filename, git_ref: Option<GitRef>) -> Result<Vec<u8>, GitErr>;
+}
+
+impl<C: SimpleClient> Contenter for C {
+  fn get_contents(&self, repo: Repository, path: Filename, query: Option<ContentsQuery>) -> Result<Content, GitErr> {
+    let url = url_builders::contents_at(&repo, &path);
+    match query {
+      Some(query) => self.request_with_query(Method::Get, url, query),
+      None => self.request_without_payload(Method::Get, url)
+    }
+  }
+
+  fn list_directory(&self, repo: Repository, path: Filename, query: Option<ContentsQuery>) -> Result<Vec<Content>, GitErr> {
+    let url = url_builders::contents_at(&repo, &path);
+    match query {
+      Some(query) => self.request_with_query(Method::Get, url, query),
+      None => self.request_without_payload(Method::Get, url)
+    }
+  }
+
+  fn get_readme(&self, repo: Repository, query: Option<ContentsQuery>) -> Result<Content, GitErr> {
+    let url = url_builders::readme_at(&repo);
+    match query {
+      Some(query) => self.request_with_query(Method::Get, url, query),
+      None => self.request_without_payload(Method::Get, url)
+    }
+  }
+
+  fn create_file(&self, repo: Repository, path: Filename, details: CreateFile) -> Result<FileCommit, GitErr> {
+    let url = url_builders::contents_at(&repo, &path);
+    self.request_with_payload(Method::Put, url, details)
+  }
+
+  fn update_file(&self, repo: Repository, path: Filename, details: UpdateFile) -> Result<FileCommit, GitErr> {
+    let url = url_builders::contents_at(&repo, &path);
+    self.request_with_payload(Method::Put, url, details)
+  }
+
+  fn delete_file(&self, repo: Repository, path: Filename, details: DeleteFile) -> Result<FileCommit, GitErr> {
+    let url = url_builders::contents_at(&repo, &path);
+    self.request_with_payload(Method::Delete, url, details)
+  }
+
+  fn get_file_bytes(&self, repo: Repository, path: Filename, git_ref: Option<GitRef>) -> Result<Vec<u8>, GitErr> {
+    let query = ContentsQuery { git_ref: git_ref };
+    self
+      .get_contents(repo, path, Some(query))
+      .and_then(|content| content.decoded_content())
+  }
+}
@@ -0,0 +1,62 @@
+use types::{
+  Url,
+  Filename,
+};
+use types::repos::Repository;
+
+use github_client::percent_encode;
+use repos::url_builders::repo_at;
+
+pub fn contents_at(repo: &Repository, path: &Filename) -> Url {
+  let segments: Vec<String> = path
+    .trim_left_matches("/")
+    .split("/")
+    .map(percent_encode)
+    .collect();
+  repo_at(repo) + "/contents/" + &segments.join("/")
+}
+
+pub fn readme_at(repo: &Repository) -> Url {
+  repo_at(repo) + "/readme"
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+
+  use types::repos::Repository;
+
+  use super::{
+    contents_at,
+    readme_at,
+  };
+
+  #[test]
+  fn it_builds_contents_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/contents/src/lib.rs";
+    expect!(contents_at(&repo, &("src/lib.rs".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_contents_at_with_leading_slash() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/contents/src/lib.rs";
+    expect!(contents_at(&repo, &("/src/lib.rs".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_contents_at_with_encoded_segments() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/contents/my%20docs/notes%231.md";
+    expect!(contents_at(&repo, &("my docs/notes#1.md".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_readme_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/readme";
+    expect!(readme_at(&repo)).to(be_equal_to(expected));
+  }
+}
@@ -0,0 +1,52 @@
+pub mod url_builders;
+
+use hyper::method::Method;
+
+use github_client::{
+  SimpleClient,
+  deleted_status,
+};
+
+use types::{
+  GitErr,
+  DeletedStatus,
+};
+
+use types::deploy_keys::{
+  DeployKeyId,
+  DeployKey,
+  CreateDeployKey,
+};
+
+use types::repos::Repository;
+
+pub trait DeployKeyer {
+  fn list_deploy_keys(&self, repo: Repository) -> Result<Vec<DeployKey>, GitErr>;
+  fn get_deploy_key(&self, repo: Repository, key_id: DeployKeyId) -> Result<DeployKey, GitErr>;
+  fn create_deploy_key(&self, repo: Repository, details: CreateDeployKey) -> Result<DeployKey, GitErr>;
+  fn delete_deploy_key(&self, repo: Repository, key_id: DeployKeyId) -> Result<DeletedStatus, GitErr>;
+}
+
+impl<C: SimpleClient> DeployKeyer for C {
+  fn list_deploy_keys(&self, repo: Repository) -> Result<Vec<DeployKey>, GitErr> {
+    let url = url_builders::keys_at(&repo);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn get_deploy_key(&self, repo: Repository, key_id: DeployKeyId) -> Result<DeployKey, GitErr> {
+    let url = url_builders::key_at(&repo, &key_id);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn create_deploy_key(&self, repo: Repository, details: CreateDeployKey) -> Result<DeployKey, GitErr> {
+    let url = url_builders::keys_at(&repo);
+    self.request_with_payload(Method::Post, url, details)
+  }
+
+  fn delete_deploy_key(&self, repo: Repository, key_id: DeployKeyId) -> Result<DeletedStatus, GitErr> {
+    let url = url_builders::key_at(&repo, &key_id);
+    self
+      .request_for_status(Method::Delete, url)
+      .and_then(deleted_status)
+  }
+}
@@ -0,0 +1,40 @@
+use types::Url;
+use types::deploy_keys::DeployKeyId;
+use types::repos::Repository;
+
+use repos::url_builders::repo_at;
+
+pub fn keys_at(repo: &Repository) -> Url {
+  repo_at(repo) + "/keys"
+}
+
+pub fn key_at(repo: &Repository, key_id: &DeployKeyId) -> Url {
+  keys_at(repo) + "/" + &key_id.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+
+  use types::repos::Repository;
+
+  use super::{
+    keys_at,
+    key_at,
+  };
+
+  #[test]
+  fn it_builds_keys_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/keys";
+    expect!(keys_at(&repo)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_key_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/keys/42";
+    expect!(key_at(&repo, &42)).to(be_equal_to(expected));
+  }
+}
@@ -0,0 +1,63 @@
+pub mod url_builders;
+
+use hyper::method::Method;
+
+use github_client::{SimpleClient};
+
+use types::GitErr;
+
+use types::deployments::{
+  DeploymentId,
+  Deployment,
+  CreateDeployment,
+  DeploymentsQuery,
+  DeploymentStatus,
+  CreateDeploymentStatus,
+  EnvironmentList,
+};
+
+use types::repos::Repository;
+
+pub trait Deployer {
+  fn create_deployment(&self, repo: Repository, details: CreateDeployment) -> Result<Deployment, GitErr>;
+  fn list_deployments(&self, repo: Repository, query: Option<DeploymentsQuery>) -> Result<Vec<Deployment>, GitErr>;
+  fn get_deployment(&self, repo: Repository, deployment_id: DeploymentId) -> Result<Deployment, GitErr>;
+  fn create_deployment_status(&self, repo: Repository, deployment_id: DeploymentId, details: CreateDeploymentStatus) -> Result<DeploymentStatus, GitErr>;
+  fn list_deployment_statuses(&self, repo: Repository, deployment_id: DeploymentId) -> Result<Vec<DeploymentStatus>, GitErr>;
+  fn list_environments(&self, repo: Repository) -> Result<EnvironmentList, GitErr>;
+}
+
+impl<C: SimpleClient> Deployer for C {
+  fn create_deployment(&self, repo: Repository, details: CreateDeployment) -> Result<Deployment, GitErr> {
+    let url = url_builders::deployments(&repo);
+    self.request_with_payload(Method::Post, url, details)
+  }
+
+  fn list_deployments(&self, repo: Repository, query: Option<DeploymentsQuery>) -> Result<Vec<Deployment>, GitErr> {
+    let url = url_builders::deployments(&repo);
+    match query {
+      Some(query) => self.request_with_query(Method::Get, url, query),
+      None => self.request_without_payload(Method::Get, url)
+    }
+  }
+
+  fn get_deployment(&self, repo: Repository, deployment_id: DeploymentId) -> Result<Deployment, GitErr> {
+    let url = url_builders::deployment_at(&repo, &deployment_id);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn create_deployment_status(&self, repo: Repository, deployment_id: DeploymentId, details: CreateDeploymentStatus) -> Result<DeploymentStatus, GitErr> {
+    let url = url_builders::deployment_statuses(&repo, &deployment_id);
+    self.request_with_payload(Method::Post, url, details)
+  }
+
+  fn list_deployment_statuses(&self, repo: Repository, deployment_id: DeploymentId) -> Result<Vec<DeploymentStatus>, GitErr> {
+    let url = url_builders::deployment_statuses(&repo, &deployment_id);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn list_environments(&self, repo: Repository) -> Result<EnvironmentList, GitErr> {
+    let url = url_builders::environments(&repo);
+    self.request_without_payload(Method::Get, url)
+  }
+}
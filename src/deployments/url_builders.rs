@@ -0,0 +1,64 @@
+use types::Url;
+use types::deployments::DeploymentId;
+use types::repos::Repository;
+
+use repos::url_builders::repo_at;
+
+pub fn deployments(repo: &Repository) -> Url {
+  repo_at(repo) + "/deployments"
+}
+
+pub fn deployment_at(repo: &Repository, deployment_id: &DeploymentId) -> Url {
+  deployments(repo) + "/" + &deployment_id.to_string()
+}
+
+pub fn deployment_statuses(repo: &Repository, deployment_id: &DeploymentId) -> Url {
+  deployment_at(repo, deployment_id) + "/statuses"
+}
+
+pub fn environments(repo: &Repository) -> Url {
+  repo_at(repo) + "/environments"
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+
+  use types::repos::Repository;
+
+  use super::{
+    deployments,
+    deployment_at,
+    deployment_statuses,
+    environments,
+  };
+
+  #[test]
+  fn it_builds_deployments() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/deployments";
+    expect!(deployments(&repo)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_deployment_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/deployments/42";
+    expect!(deployment_at(&repo, &42)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_deployment_statuses() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/deployments/42/statuses";
+    expect!(deployment_statuses(&repo, &42)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_environments() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/environments";
+    expect!(environments(&repo)).to(be_equal_to(expected));
+  }
+}
@@ -0,0 +1,260 @@
+use std::thread;
+use std::time::Duration;
+
+use hyper::method::Method;
+use hyper::status::StatusCode;
+use rustc_serialize::base64::{
+  ToBase64,
+  STANDARD,
+};
+
+use github_client::{SimpleClient};
+use git_data::{
+  url_builders,
+  GitData,
+};
+
+use types::{
+  GitErr,
+  GitRef,
+};
+
+use types::commits::GitCommit;
+
+use types::git_data::{
+  BlobEncoding,
+  CreateBlob,
+  CreateTree,
+  CreateTreeEntry,
+  CreateGitCommit,
+  FileChange,
+  FileMode,
+  GitObjectType,
+  MultiFileCommit,
+  UpdateReference,
+};
+
+use types::repos::Repository;
+
+const MAX_REF_UPDATE_ATTEMPTS: u32 = 5;
+const INITIAL_RETRY_DELAY_MILLIS: u64 = 100;
+
+pub trait FileCommitter {
+  /// Commits all changes on top of the branch head and fast-forwards the
+  /// branch to it. If the branch moves in the meantime the commit is rebuilt
+  /// on the new head, backing off between attempts, up to
+  /// MAX_REF_UPDATE_ATTEMPTS times.
+  fn commit_files(&self, repo: Repository, details: MultiFileCommit) -> Result<GitCommit, GitErr>;
+}
+
+fn tree_entries<C: GitData>(client: &C, repo: &Repository, changes: &Vec<FileChange>) -> Result<Vec<CreateTreeEntry>, GitErr> {
+  changes
+    .iter()
+    .map(|change| match *change {
+      FileChange::Write { ref path, ref content, ref mode } => {
+        let blob = CreateBlob { content: content.to_base64(STANDARD), encoding: BlobEncoding::Base64 };
+        client
+          .create_blob(repo.clone(), blob)
+          .map(|blob| CreateTreeEntry {
+            path: path.clone(),
+            mode: mode.clone(),
+            object_type: GitObjectType::Blob,
+            sha: Some(blob.sha),
+            content: None
+          })
+      },
+      FileChange::Delete { ref path } => {
+        Ok(CreateTreeEntry {
+          path: path.clone(),
+          mode: FileMode::File,
+          object_type: GitObjectType::Blob,
+          sha: None,
+          content: None
+        })
+      }
+    })
+    .collect()
+}
+
+impl<C: SimpleClient> FileCommitter for C {
+  fn commit_files(&self, repo: Repository, details: MultiFileCommit) -> Result<GitCommit, GitErr> {
+    let branch_ref: GitRef = "heads/".to_owned() + &details.branch;
+    let entries = try!(tree_entries(self, &repo, &details.changes));
+    let mut head = try!(self.get_ref(repo.clone(), branch_ref.clone())).object.sha;
+    let mut delay = INITIAL_RETRY_DELAY_MILLIS;
+
+    for attempt in 1..(MAX_REF_UPDATE_ATTEMPTS + 1) {
+      let parent = try!(self.get_git_commit(repo.clone(), head.clone()));
+
+      let tree = try!(self.create_tree(repo.clone(), CreateTree {
+        base_tree: Some(parent.tree.sha.clone()),
+        tree: entries.clone()
+      }));
+
+      let commit = try!(self.create_git_commit(repo.clone(), CreateGitCommit {
+        message: details.message.clone(),
+        tree: tree.sha,
+        parents: vec![parent.sha],
+        author: details.author.clone(),
+        committer: details.committer.clone()
+      }));
+
+      let url = url_builders::git_ref_at(&repo, &branch_ref);
+      let update = UpdateReference { sha: commit.sha.clone(), force: false };
+      match try!(self.request_with_payload_for_status(Method::Patch, url, update)) {
+        StatusCode::Ok => return Ok(commit),
+        StatusCode::UnprocessableEntity => {
+          // Only a branch that moved since we read it is worth retrying;
+          // any other rejection would fail the same way again.
+          let current = try!(self.get_ref(repo.clone(), branch_ref.clone())).object.sha;
+          if current == head {
+            return Err(GitErr::UnexpectedStatus(StatusCode::UnprocessableEntity.to_string()));
+          }
+          head = current;
+          if attempt < MAX_REF_UPDATE_ATTEMPTS {
+            thread::sleep(Duration::from_millis(delay));
+            delay = delay * 2;
+          }
+        },
+        status => return Err(GitErr::UnexpectedStatus(status.to_string()))
+      }
+    }
+
+    Err(GitErr::RetriesExhausted(format!("{} moved during each of {} attempts to fast-forward it", branch_ref, MAX_REF_UPDATE_ATTEMPTS)))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+  use hyper::method::Method;
+  use hyper::status::StatusCode;
+
+  use test_client::{MockClient, MockResponse};
+  use types::GitErr;
+  use types::git_data::{FileChange, MultiFileCommit};
+  use types::repos::Repository;
+
+  use super::{FileCommitter, MAX_REF_UPDATE_ATTEMPTS};
+
+  fn repo() -> Repository {
+    Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() }
+  }
+
+  fn details() -> MultiFileCommit {
+    MultiFileCommit {
+      branch: "master".to_owned(),
+      message: "Remove the old readme".to_owned(),
+      changes: vec![FileChange::Delete { path: "README".to_owned() }],
+      author: None,
+      committer: None
+    }
+  }
+
+  fn reference(sha: &str) -> MockResponse {
+    MockResponse::json(&format!(r#"{{
+      "ref": "refs/heads/master",
+      "url": "https://api.github.com/repos/test_owner/test_repo/git/refs/heads/master",
+      "object": {{ "type": "commit", "sha": "{}", "url": "https://api.github.com/repos/test_owner/test_repo/git/commits/{}" }}
+    }}"#, sha, sha))
+  }
+
+  fn commit(sha: &str) -> MockResponse {
+    MockResponse::json(&format!(r#"{{
+      "sha": "{}",
+      "url": "https://api.github.com/repos/test_owner/test_repo/git/commits/{}",
+      "html_url": null,
+      "author": {{ "name": "Test", "email": "test@example.com", "date": "2020-01-01T00:00:00Z" }},
+      "committer": {{ "name": "Test", "email": "test@example.com", "date": "2020-01-01T00:00:00Z" }},
+      "message": "a commit",
+      "tree": {{ "url": "https://api.github.com/repos/test_owner/test_repo/git/trees/tree1", "sha": "tree1" }},
+      "parents": []
+    }}"#, sha, sha))
+  }
+
+  fn tree() -> MockResponse {
+    MockResponse::json(r#"{
+      "sha": "tree2",
+      "url": "https://api.github.com/repos/test_owner/test_repo/git/trees/tree2",
+      "tree": [],
+      "truncated": false
+    }"#)
+  }
+
+  // Reads the parent, builds a tree and a commit on it and tries the update
+  fn attempt(parent: &str, created: &str, update: StatusCode) -> Vec<MockResponse> {
+    vec![commit(parent), tree(), commit(created), MockResponse::status(update)]
+  }
+
+  #[test]
+  fn it_commits_on_the_first_attempt() {
+    let mut responses = vec![reference("head1")];
+    responses.extend(attempt("head1", "new1", StatusCode::Ok));
+    let client = MockClient::new(responses);
+
+    let commit = client.commit_files(repo(), details()).unwrap();
+
+    expect!(commit.sha).to(be_equal_to("new1".to_owned()));
+    let requests = client.requests.borrow();
+    expect!(requests[4].method.clone()).to(be_equal_to(Method::Patch));
+    expect!(requests[4].url.clone()).to(be_equal_to("https://api.github.com/repos/test_owner/test_repo/git/refs/heads/master".to_owned()));
+    expect!(requests[4].body.clone()).to(be_equal_to(Some(r#"{"sha":"new1","force":false}"#.to_owned())));
+  }
+
+  #[test]
+  fn it_leaves_an_unset_author_and_committer_out_of_the_commit() {
+    let mut responses = vec![reference("head1")];
+    responses.extend(attempt("head1", "new1", StatusCode::Ok));
+    let client = MockClient::new(responses);
+
+    client.commit_files(repo(), details()).unwrap();
+
+    let requests = client.requests.borrow();
+    expect!(requests[2].body.clone()).to(be_equal_to(Some(r#"{"tree":[{"path":"README","mode":"100644","type":"blob","sha":null}],"base_tree":"tree1"}"#.to_owned())));
+    expect!(requests[3].body.clone()).to(be_equal_to(Some(r#"{"message":"Remove the old readme","tree":"tree2","parents":["head1"]}"#.to_owned())));
+  }
+
+  #[test]
+  fn it_rebuilds_the_commit_when_the_branch_moves() {
+    let mut responses = vec![reference("head1")];
+    responses.extend(attempt("head1", "new1", StatusCode::UnprocessableEntity));
+    responses.push(reference("head2"));
+    responses.extend(attempt("head2", "new2", StatusCode::Ok));
+    let client = MockClient::new(responses);
+
+    let commit = client.commit_files(repo(), details()).unwrap();
+
+    expect!(commit.sha).to(be_equal_to("new2".to_owned()));
+    expect!(client.requests.borrow()[6].url.clone()).to(be_equal_to("https://api.github.com/repos/test_owner/test_repo/git/commits/head2".to_owned()));
+  }
+
+  #[test]
+  fn it_does_not_retry_a_rejection_when_the_branch_has_not_moved() {
+    let mut responses = vec![reference("head1")];
+    responses.extend(attempt("head1", "new1", StatusCode::UnprocessableEntity));
+    responses.push(reference("head1"));
+    let client = MockClient::new(responses);
+
+    let result = client.commit_files(repo(), details());
+
+    expect!(result.is_err()).to(be_equal_to(true));
+    expect!(client.request_count()).to(be_equal_to(6));
+  }
+
+  #[test]
+  fn it_gives_up_when_the_branch_keeps_moving() {
+    let mut responses = vec![reference("head0")];
+    for attempt_number in 0..MAX_REF_UPDATE_ATTEMPTS {
+      let parent = format!("head{}", attempt_number);
+      responses.extend(attempt(&parent, "new", StatusCode::UnprocessableEntity));
+      responses.push(reference(&format!("head{}", attempt_number + 1)));
+    }
+    let client = MockClient::new(responses);
+
+    match client.commit_files(repo(), details()) {
+      Err(GitErr::RetriesExhausted(_)) => (),
+      other => panic!("expected RetriesExhausted, got {:?}", other)
+    }
+  }
+}
@@ -0,0 +1,99 @@
+pub mod url_builders;
+
+use hyper::method::Method;
+use hyper::status::StatusCode;
+
+use github_client::{
+  SimpleClient,
+  deleted_status,
+};
+
+use types::{
+  Url,
+  GitErr,
+  PageQuery,
+  DeletedStatus,
+};
+
+use types::users::{
+  UserName,
+  GithubUser,
+  FollowingStatus,
+  FollowedStatus,
+};
+
+pub trait Follower {
+  fn list_own_followers(&self, page: Option<PageQuery>) -> Result<Vec<GithubUser>, GitErr>;
+  fn list_own_following(&self, page: Option<PageQuery>) -> Result<Vec<GithubUser>, GitErr>;
+  fn list_followers(&self, user: UserName, page: Option<PageQuery>) -> Result<Vec<GithubUser>, GitErr>;
+  fn list_following(&self, user: UserName, page: Option<PageQuery>) -> Result<Vec<GithubUser>, GitErr>;
+  fn is_following(&self, target: UserName) -> Result<FollowingStatus, GitErr>;
+  fn user_is_following(&self, user: UserName, target: UserName) -> Result<FollowingStatus, GitErr>;
+  fn follow(&self, target: UserName) -> Result<FollowedStatus, GitErr>;
+  fn unfollow(&self, target: UserName) -> Result<DeletedStatus, GitErr>;
+}
+
+fn list_users<C: SimpleClient>(client: &C, url: Url, page: Option<PageQuery>) -> Result<Vec<GithubUser>, GitErr> {
+  match page {
+    Some(page) => client.request_with_query(Method::Get, url, page),
+    None => client.request_without_payload(Method::Get, url)
+  }
+}
+
+fn following_status(status: StatusCode) -> Result<FollowingStatus, GitErr> {
+  match status {
+    StatusCode::NoContent => Ok(FollowingStatus::Following),
+    StatusCode::NotFound => Ok(FollowingStatus::NotFollowing),
+    status => Err(GitErr::UnexpectedStatus(status.to_string()))
+  }
+}
+
+impl<C: SimpleClient> Follower for C {
+  fn list_own_followers(&self, page: Option<PageQuery>) -> Result<Vec<GithubUser>, GitErr> {
+    list_users(self, url_builders::own_followers(), page)
+  }
+
+  fn list_own_following(&self, page: Option<PageQuery>) -> Result<Vec<GithubUser>, GitErr> {
+    list_users(self, url_builders::own_following(), page)
+  }
+
+  fn list_followers(&self, user: UserName, page: Option<PageQuery>) -> Result<Vec<GithubUser>, GitErr> {
+    list_users(self, url_builders::user_followers(&user), page)
+  }
+
+  fn list_following(&self, user: UserName, page: Option<PageQuery>) -> Result<Vec<GithubUser>, GitErr> {
+    list_users(self, url_builders::user_following(&user), page)
+  }
+
+  fn is_following(&self, target: UserName) -> Result<FollowingStatus, GitErr> {
+    let url = url_builders::own_following_user(&target);
+    self
+      .request_for_status(Method::Get, url)
+      .and_then(following_status)
+  }
+
+  fn user_is_following(&self, user: UserName, target: UserName) -> Result<FollowingStatus, GitErr> {
+    let url = url_builders::user_following_user(&user, &target);
+    self
+      .request_for_status(Method::Get, url)
+      .and_then(following_status)
+  }
+
+  fn follow(&self, target: UserName) -> Result<FollowedStatus, GitErr> {
+    let url = url_builders::own_following_user(&target);
+    self
+      .request_for_status(Method::Put, url)
+      .and_then(|status| match status {
+        StatusCode::NoContent => Ok(FollowedStatus::Followed),
+        StatusCode::Forbidden | StatusCode::NotFound => Ok(FollowedStatus::NotFollowed),
+        status => Err(GitErr::UnexpectedStatus(status.to_string()))
+      })
+  }
+
+  fn unfollow(&self, target: UserName) -> Result<DeletedStatus, GitErr> {
+    let url = url_builders::own_following_user(&target);
+    self
+      .request_for_status(Method::Delete, url)
+      .and_then(deleted_status)
+  }
+}
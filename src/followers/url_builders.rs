@@ -0,0 +1,82 @@
+use types::Url;
+use types::users::UserName;
+
+use users::url_builders::{
+  authenticated_user,
+  user_at,
+};
+
+pub fn own_followers() -> Url {
+  authenticated_user() + "/followers"
+}
+
+pub fn own_following() -> Url {
+  authenticated_user() + "/following"
+}
+
+pub fn own_following_user(target: &UserName) -> Url {
+  own_following() + "/" + target
+}
+
+pub fn user_followers(user: &UserName) -> Url {
+  user_at(user) + "/followers"
+}
+
+pub fn user_following(user: &UserName) -> Url {
+  user_at(user) + "/following"
+}
+
+pub fn user_following_user(user: &UserName, target: &UserName) -> Url {
+  user_following(user) + "/" + target
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+
+  use super::{
+    own_followers,
+    own_following,
+    own_following_user,
+    user_followers,
+    user_following,
+    user_following_user,
+  };
+
+  #[test]
+  fn it_builds_own_followers() {
+    let expected = "https://api.github.com/user/followers";
+    expect!(own_followers()).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_own_following() {
+    let expected = "https://api.github.com/user/following";
+    expect!(own_following()).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_own_following_user() {
+    let expected = "https://api.github.com/user/following/test_target";
+    expect!(own_following_user(&("test_target".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_user_followers() {
+    let expected = "https://api.github.com/users/test_user/followers";
+    expect!(user_followers(&("test_user".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_user_following() {
+    let expected = "https://api.github.com/users/test_user/following";
+    expect!(user_following(&("test_user".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_user_following_user() {
+    let expected = "https://api.github.com/users/test_user/following/test_target";
+    expect!(user_following_user(&("test_user".to_owned()), &("test_target".to_owned()))).to(be_equal_to(expected));
+  }
+}
@@ -0,0 +1,163 @@
+pub mod url_builders;
+
+use hyper::method::Method;
+use hyper::status::StatusCode;
+
+use commits::url_builders as commits_url_builders;
+use github_client::{
+  SimpleClient,
+  url_with_query,
+};
+use polling::poll_until_ready;
+use repos::Repoer;
+
+use types::{
+  GitErr,
+  PageQuery,
+  PollOptions,
+};
+
+use types::repos::{
+  Repo,
+  Repository,
+  ForksQuery,
+  CreateFork,
+};
+
+pub trait Forker {
+  fn list_forks(&self, repo: Repository, query: Option<ForksQuery>) -> Result<Vec<Repo>, GitErr>;
+  fn create_fork(&self, repo: Repository, details: CreateFork) -> Result<Repo, GitErr>;
+  fn wait_for_fork(&self, fork: Repository, options: PollOptions) -> Result<Repo, GitErr>;
+  fn create_fork_and_wait(&self, repo: Repository, details: CreateFork, options: PollOptions) -> Result<Repo, GitErr>;
+}
+
+impl<C: SimpleClient> Forker for C {
+  fn list_forks(&self, repo: Repository, query: Option<ForksQuery>) -> Result<Vec<Repo>, GitErr> {
+    let url = url_builders::forks_at(&repo);
+    match query {
+      Some(query) => self.request_with_query(Method::Get, url, query),
+      None => self.request_without_payload(Method::Get, url)
+    }
+  }
+
+  fn create_fork(&self, repo: Repository, details: CreateFork) -> Result<Repo, GitErr> {
+    let url = url_builders::forks_at(&repo);
+    self.request_with_payload(Method::Post, url, details)
+  }
+
+  // Forks are created asynchronously, and the fork record exists before its
+  // git data has been copied. Until then listing its commits answers 404
+  // before the record exists and 409, as for an empty repository, while the
+  // copy is still running.
+  fn wait_for_fork(&self, fork: Repository, options: PollOptions) -> Result<Repo, GitErr> {
+    let description = "fork ".to_owned() + &fork.owner + "/" + &fork.repo_name;
+    let url = try!(url_with_query(commits_url_builders::commits(&fork), &PageQuery { per_page: Some(1), page: None }));
+    try!(poll_until_ready(&options, &description, || {
+      match try!(self.request_for_status(Method::Get, url.clone())) {
+        StatusCode::Ok => Ok(Some(())),
+        StatusCode::NotFound | StatusCode::Conflict => Ok(None),
+        status => Err(GitErr::UnexpectedStatus(status.to_string()))
+      }
+    }));
+    self.get_repo(fork)
+  }
+
+  fn create_fork_and_wait(&self, repo: Repository, details: CreateFork, options: PollOptions) -> Result<Repo, GitErr>;
+}
+
+impl<C: SimpleClient> Forker for C {
+  fn list_forks(&self, repo: Repository, query: Option<ForksQuery>) -> Result<Vec<Repo>, GitErr> {
+    let url = url_builders::forks_at(&repo);
+    match query {
+      Some(query) => self.request_with_query(Method::Get, url, query),
+      None => self.request_without_payload(Method::Get, url)
+    }
+  }
+
+  fn create_fork(&self, repo: Repository, details: CreateFork) -> Result<Repo, GitErr> {
+    let url = url_builders::forks_at(&repo);
+    self.request_with_payload(Method::Post, url, details)
+  }
+
+  // Forks are created asynchronously. Until the fork can be fetched Github
+  // answers 404, or 409 while its git data is still being copied.
+  fn wait_for_fork(&self, fork: Repository, options: PollOptions) -> Result<Repo, GitErr> {
+    let description = "fork ".to_owned() + &fork.owner + "/" + &fork.repo_name;
+    let url = repo_at(&fork);
+    try!(poll_until_ready(&options, &description, || {
+      match try!(self.request_for_status(Method::Get, url.clone())) {
+        StatusCode::Ok => Ok(Some(())),
+        StatusCode::NotFound | StatusCode::Conflict => Ok(None),
+        status => Err(GitErr::UnexpectedStatus(status.to_string()))
+      }
+    }));
+    self.get_repo(fork)
+  }
+
+  fn create_fork_and_wait(&self, repo: Repository, details: CreateFork, options: PollOptions) -> Result<Repo, GitErr> {
+    self
+      .create_fork(repo, details)
+      .and_then(|fork| {
+        let fork = Repository { owner: fork.owner.login, repo_name: fork.name };
+        self.wait_for_fork(fork, options)
+      })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::time::Duration;
+
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+  use hyper::status::StatusCode;
+
+  use test_client::{MockClient, MockResponse, repo_json};
+  use types::{GitErr, PollOptions};
+  use types::repos::{Repository, CreateFork};
+
+  use super::Forker;
+
+  fn fork() -> Repository {
+    Repository { owner: "fork_owner".to_owned(), repo_name: "test_repo".to_owned() }
+  }
+
+  fn options() -> PollOptions {
+    PollOptions { timeout: Duration::from_millis(100), initial_interval: Duration::from_millis(1), max_interval: Duration::from_millis(1) }
+  }
+
+  #[test]
+  fn it_waits_until_the_fork_has_commits() {
+    let client = MockClient::new(vec![
+      MockResponse::status(StatusCode::NotFound),
+      MockResponse::status(StatusCode::Conflict),
+      MockResponse::status(StatusCode::Ok),
+      MockResponse::json(&repo_json("fork_owner", "test_repo")),
+    ]);
+
+    let repo = client.wait_for_fork(fork(), options()).unwrap();
+
+    expect!(repo.full_name).to(be_equal_to("fork_owner/test_repo".to_owned()));
+    let requests = client.requests.borrow();
+    expect!(requests.len()).to(be_equal_to(4));
+    expect!(requests[0].url.clone()).to(be_equal_to("https://api.github.com/repos/fork_owner/test_repo/commits?per_page=1".to_owned()));
+    expect!(requests[3].url.clone()).to(be_equal_to("https://api.github.com/repos/fork_owner/test_repo".to_owned()));
+  }
+
+  #[test]
+  fn it_stops_waiting_on_an_unexpected_status() {
+    let client = MockClient::new(vec![MockResponse::status(StatusCode::Forbidden)]);
+    let result = client.wait_for_fork(fork(), options());
+    expect!(result.map(|repo| repo.full_name)).to(be_equal_to(Err(GitErr::UnexpectedStatus(StatusCode::Forbidden.to_string()))));
+  }
+
+  #[test]
+  fn it_forks_to_the_own_account_without_nulls() {
+    let client = MockClient::new(vec![MockResponse::json(&repo_json("fork_owner", "test_repo"))]);
+    let details = CreateFork { organization: None, name: None, default_branch_only: None };
+
+    client.create_fork(Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() }, details).unwrap();
+
+    expect!(client.requests.borrow()[0].body.clone()).to(be_equal_to(Some("{}".to_owned())));
+  }
+}
@@ -0,0 +1,25 @@
+use types::Url;
+use types::repos::Repository;
+
+use repos::url_builders::repo_at;
+
+pub fn forks_at(repo: &Repository) -> Url {
+  repo_at(repo) + "/forks"
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+
+  use types::repos::Repository;
+
+  use super::forks_at;
+
+  #[test]
+  fn it_builds_forks_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/forks";
+    expect!(forks_at(&repo)).to(be_equal_to(expected));
+  }
+}
@@ -0,0 +1,124 @@
+pub mod url_builders;
+
+use hyper::method::Method;
+
+use github_client::{
+  SimpleClient,
+  deleted_status,
+};
+
+use types::{
+  GitErr,
+  GitRef,
+  Sha,
+  DeletedStatus,
+};
+
+use types::commits::{
+  CommitTreeNode,
+  GitCommit,
+};
+
+use types::git_data::{
+  Blob,
+  CreateBlob,
+  Tree,
+  CreateTree,
+  CreateGitCommit,
+  Reference,
+  CreateReference,
+  UpdateReference,
+  AnnotatedTag,
+  CreateAnnotatedTag,
+};
+
+use types::repos::Repository;
+
+pub trait GitData {
+  fn get_blob(&self, repo: Repository, sha: Sha) -> Result<Blob, GitErr>;
+  fn create_blob(&self, repo: Repository, details: CreateBlob) -> Result<CommitTreeNode, GitErr>;
+  fn get_tree(&self, repo: Repository, sha: Sha, recursive: bool) -> Result<Tree, GitErr>;
+  fn create_tree(&self, repo: Repository, details: CreateTree) -> Result<Tree, GitErr>;
+  fn get_git_commit(&self, repo: Repository, sha: Sha) -> Result<GitCommit, GitErr>;
+  fn create_git_commit(&self, repo: Repository, details: CreateGitCommit) -> Result<GitCommit, GitErr>;
+  fn get_ref(&self, repo: Repository, git_ref: GitRef) -> Result<Reference, GitErr>;
+  fn list_matching_refs(&self, repo: Repository, git_ref: GitRef) -> Result<Vec<Reference>, GitErr>;
+  fn create_ref(&self, repo: Repository, details: CreateReference) -> Result<Reference, GitErr>;
+  fn update_ref(&self, repo: Repository, git_ref: GitRef, details: UpdateReference) -> Result<Reference, GitErr>;
+  fn delete_ref(&self, repo: Repository, git_ref: GitRef) -> Result<DeletedStatus, GitErr>;
+  fn get_git_tag(&self, repo: Repository, sha: Sha) -> Result<AnnotatedTag, GitErr>;
+  fn create_git_tag(&self, repo: Repository, details: CreateAnnotatedTag) -> Result<AnnotatedTag, GitErr>;
+}
+
+impl<C: SimpleClient> GitData for C {
+  fn get_blob(&self, repo: Repository, sha: Sha) -> Result<Blob, GitErr> {
+    let url = url_builders::blob_at(&repo, &sha);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn create_blob(&self, repo: Repository, details: CreateBlob) -> Result<CommitTreeNode, GitErr> {
+    let url = url_builders::blobs(&repo);
+    self.request_with_payload(Method::Post, url, details)
+  }
+
+  fn get_tree(&self, repo: Repository, sha: Sha, recursive: bool) -> Result<Tree, GitErr> {
+    let url = if recursive {
+      url_builders::recursive_tree_at(&repo, &sha)
+    } else {
+      url_builders::tree_at(&repo, &sha)
+    };
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn create_tree(&self, repo: Repository, details: CreateTree) -> Result<Tree, GitErr> {
+    let url = url_builders::trees(&repo);
+    self.request_with_payload(Method::Post, url, details)
+  }
+
+  fn get_git_commit(&self, repo: Repository, sha: Sha) -> Result<GitCommit, GitErr> {
+    let url = url_builders::git_commit_at(&repo, &sha);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn create_git_commit(&self, repo: Repository, details: CreateGitCommit) -> Result<GitCommit, GitErr> {
+    let url = url_builders::git_commits(&repo);
+    self.request_with_payload(Method::Post, url, details)
+  }
+
+  fn get_ref(&self, repo: Repository, git_ref: GitRef) -> Result<Reference, GitErr> {
+    let url = url_builders::single_git_ref_at(&repo, &git_ref);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn list_matching_refs(&self, repo: Repository, git_ref: GitRef) -> Result<Vec<Reference>, GitErr> {
+    let url = url_builders::matching_git_refs(&repo, &git_ref);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn create_ref(&self, repo: Repository, details: CreateReference) -> Result<Reference, GitErr> {
+    let url = url_builders::git_refs(&repo);
+    self.request_with_payload(Method::Post, url, details)
+  }
+
+  fn update_ref(&self, repo: Repository, git_ref: GitRef, details: UpdateReference) -> Result<Reference, GitErr> {
+    let url = url_builders::git_ref_at(&repo, &git_ref);
+    self.request_with_payload(Method::Patch, url, details)
+  }
+
+  fn delete_ref(&self, repo: Repository, git_ref: GitRef) -> Result<DeletedStatus, GitErr> {
+    let url = url_builders::git_ref_at(&repo, &git_ref);
+    self
+      .request_for_status(Method::Delete, url)
+      .and_then(deleted_status)
+  }
+
+  fn get_git_tag(&self, repo: Repository, sha: Sha) -> Result<AnnotatedTag, GitErr> {
+    let url = url_builders::git_tag_at(&repo, &sha);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn create_git_tag(&self, repo: Repository, details: CreateAnnotatedTag) -> Result<AnnotatedTag, GitErr> {
+    let url = url_builders::git_tags(&repo);
+    self.request_with_payload(Method::Post, url, details)
+  }
+}
@@ -0,0 +1,187 @@
+use types::{
+  Url,
+  Sha,
+  GitRef,
+};
+use types::repos::Repository;
+
+use repos::url_builders::repo_at;
+
+// Refs are addressed without their leading "refs/" in urls
+fn ref_path(git_ref: &GitRef) -> &str {
+  git_ref.trim_left_matches("refs/")
+}
+
+pub fn blobs(repo: &Repository) -> Url {
+  repo_at(repo) + "/git/blobs"
+}
+
+pub fn blob_at(repo: &Repository, sha: &Sha) -> Url {
+  blobs(repo) + "/" + sha
+}
+
+pub fn trees(repo: &Repository) -> Url {
+  repo_at(repo) + "/git/trees"
+}
+
+pub fn tree_at(repo: &Repository, sha: &Sha) -> Url {
+  trees(repo) + "/" + sha
+}
+
+pub fn recursive_tree_at(repo: &Repository, sha: &Sha) -> Url {
+  tree_at(repo, sha) + "?recursive=1"
+}
+
+pub fn git_commits(repo: &Repository) -> Url {
+  repo_at(repo) + "/git/commits"
+}
+
+pub fn git_commit_at(repo: &Repository, sha: &Sha) -> Url {
+  git_commits(repo) + "/" + sha
+}
+
+pub fn git_refs(repo: &Repository) -> Url {
+  repo_at(repo) + "/git/refs"
+}
+
+pub fn git_ref_at(repo: &Repository, git_ref: &GitRef) -> Url {
+  git_refs(repo) + "/" + ref_path(git_ref)
+}
+
+pub fn single_git_ref_at(repo: &Repository, git_ref: &GitRef) -> Url {
+  repo_at(repo) + "/git/ref/" + ref_path(git_ref)
+}
+
+pub fn matching_git_refs(repo: &Repository, git_ref: &GitRef) -> Url {
+  repo_at(repo) + "/git/matching-refs/" + ref_path(git_ref)
+}
+
+pub fn git_tags(repo: &Repository) -> Url {
+  repo_at(repo) + "/git/tags"
+}
+
+pub fn git_tag_at(repo: &Repository, sha: &Sha) -> Url {
+  git_tags(repo) + "/" + sha
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+
+  use types::repos::Repository;
+
+  use super::{
+    blobs,
+    blob_at,
+    trees,
+    tree_at,
+    recursive_tree_at,
+    git_commits,
+    git_commit_at,
+    git_refs,
+    git_ref_at,
+    single_git_ref_at,
+    matching_git_refs,
+    git_tags,
+    git_tag_at,
+  };
+
+  #[test]
+  fn it_builds_blobs() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/git/blobs";
+    expect!(blobs(&repo)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_blob_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/git/blobs/abc123";
+    expect!(blob_at(&repo, &("abc123".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_trees() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/git/trees";
+    expect!(trees(&repo)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_tree_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/git/trees/abc123";
+    expect!(tree_at(&repo, &("abc123".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_recursive_tree_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/git/trees/abc123?recursive=1";
+    expect!(recursive_tree_at(&repo, &("abc123".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_git_commits() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/git/commits";
+    expect!(git_commits(&repo)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_git_commit_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/git/commits/abc123";
+    expect!(git_commit_at(&repo, &("abc123".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_git_refs() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/git/refs";
+    expect!(git_refs(&repo)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_git_ref_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/git/refs/heads/master";
+    expect!(git_ref_at(&repo, &("heads/master".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_git_ref_at_from_full_ref() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/git/refs/heads/master";
+    expect!(git_ref_at(&repo, &("refs/heads/master".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_single_git_ref_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/git/ref/heads/master";
+    expect!(single_git_ref_at(&repo, &("heads/master".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_matching_git_refs() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/git/matching-refs/tags/v1";
+    expect!(matching_git_refs(&repo, &("tags/v1".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_git_tags() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/git/tags";
+    expect!(git_tags(&repo)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_git_tag_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/git/tags/abc123";
+    expect!(git_tag_at(&repo, &("abc123".to_owned()))).to(be_equal_to(expected));
+  }
+}
@@ -1,29 +1,41 @@
 pub use self::github_client::{
   GithubClient,
-  SimpleClient
+  SimpleClient,
+  url_with_query,
+  percent_encode,
+  deleted_status
 };
 
 pub mod github_client {
   use hyper::Client;
-  use hyper::header::{Accept, Authorization, Connection, qitem, Scheme, UserAgent};
+  use hyper::header::{Accept, Authorization, Connection, ContentType, Headers, Location, qitem, Scheme, UserAgent};
   use hyper::mime::{Mime, TopLevel, SubLevel};
   use hyper::method::Method;
+  use hyper::status::StatusCode;
   use hyper::client::response::Response;
   use hyper::error::Error as HyperError;
-  use hyper::client::{RequestBuilder, IntoUrl};
+  use hyper::client::{RedirectPolicy, RequestBuilder, IntoUrl};
+  use hyper::client::Body as RequestBody;
   use rustc_serialize::json::{DecoderError, EncoderError};
+  use rustc_serialize::json::Json;
   use rustc_serialize::{
     json,
     Encodable,
     Decodable
   };
 
-  use types::{GitErr, Url, Body};
-  use std::io::Read;
+  use types::{GitErr, Url, Body, PollResponse, RateLimitedResponse, DeletedStatus};
+  use std::io;
+  use std::io::{Read, Write};
   use std::any::Any;
+  use std::time::Duration;
+
+  // Hops followed by request_to_writer before giving up
+  const MAX_DOWNLOAD_REDIRECTS: u32 = 5;
 
   pub struct GithubClient<S: Scheme + Any> where S::Err: 'static {
     client: Client,
+    downloader: Client,
     token: Option<Authorization<S>>
   }
 
@@ -39,6 +51,22 @@ pub mod github_client {
     GitErr::EncodeErr(err.to_string())
   }
 
+  fn io_err_to_git_err(err: io::Error) -> GitErr {
+    GitErr::NetworkErr(err.to_string())
+  }
+
+  fn raw_header(response: &Response, name: &str) -> Option<String> {
+    response
+      .headers
+      .get_raw(name)
+      .and_then(|values| values.first())
+      .and_then(|value| String::from_utf8(value.clone()).ok())
+  }
+
+  fn github_json() -> Mime {
+    Mime(TopLevel::Application, SubLevel::Ext("vnd.github.v3+json".to_owned()), vec![])
+  }
+
   fn deserialize<S: Decodable>(response: Response) -> Result<S, GitErr> {
     let mut response = response;
     let mut buf = String::new();
@@ -47,15 +75,91 @@ pub mod github_client {
     json::decode(&buf).map_err(decode_err_to_git_err)
   }
 
+  /// Percent-encodes everything but the unreserved characters of RFC 3986
+  pub fn percent_encode(value: &str) -> String {
+    value
+      .bytes()
+      .map(|byte| match byte {
+        b'A'...b'Z' | b'a'...b'z' | b'0'...b'9' | b'-' | b'_' | b'.' | b'~' => (byte as char).to_string(),
+        _ => format!("%{:02X}", byte)
+      })
+      .collect()
+  }
+
+  fn query_value(value: &Json) -> Option<String> {
+    match *value {
+      Json::Null => None,
+      Json::String(ref string) => Some(string.clone()),
+      Json::Array(ref items) => {
+        let values: Vec<String> = items.iter().filter_map(query_value).collect();
+        Some(values.join(","))
+      },
+      ref other => Some(other.to_string())
+    }
+  }
+
+  /// Appends an encodable query to a url as url parameters, skipping any
+  /// fields that are None.
+  pub fn url_with_query<E: Encodable>(url: Url, query: &E) -> Result<Url, GitErr> {
+    json::encode(query)
+      .map_err(encode_err_to_git_err)
+      .and_then(|encoded| Json::from_str(&encoded).map_err(|err| GitErr::EncodeErr(err.to_string())))
+      .map(|encoded| {
+        let params: Vec<String> = match encoded {
+          Json::Object(fields) => {
+            fields
+              .iter()
+              .filter_map(|(key, value)| query_value(value).map(|value| percent_encode(key) + "=" + &percent_encode(&value)))
+              .collect()
+          },
+          _ => Vec::new()
+        };
+
+        if params.is_empty() {
+          url
+        } else {
+          let separator = if url.contains("?") { "&" } else { "?" };
+          url + separator + &params.join("&")
+        }
+      })
+  }
+
+  /// Maps the status of a DELETE request. 403 (not allowed) and 404 (not
+  /// found) leave the resource in place; any other status is an error.
+  pub fn deleted_status(status: StatusCode) -> Result<DeletedStatus, GitErr> {
+    match status {
+      StatusCode::NoContent => Ok(DeletedStatus::Deleted),
+      StatusCode::Forbidden | StatusCode::NotFound => Ok(DeletedStatus::NotDeleted),
+      status => Err(GitErr::UnexpectedStatus(status.to_string()))
+    }
+  }
+
   // TODO: Remove
   pub trait SimpleClient {
     fn request_without_payload<D: Decodable>(&self, method: Method, url: Url) -> Result<D, GitErr>;
     fn request_with_payload<D: Decodable, E: Encodable>(&self, method: Method, url: Url, body: E) -> Result<D, GitErr>;
+    fn request_for_status(&self, method: Method, url: Url) -> Result<StatusCode, GitErr>;
+    fn request_with_payload_for_status<E: Encodable>(&self, method: Method, url: Url, body: E) -> Result<StatusCode, GitErr>;
+    fn request_with_raw_payload<D: Decodable, R: Read>(&self, method: Method, url: Url, content_type: Mime, body: &mut R, length: u64) -> Result<D, GitErr>;
+    fn request_to_writer<W: Write>(&self, method: Method, url: Url, accept: Mime, out: &mut W) -> Result<u64, GitErr>;
+    fn request_if_ready<D: Decodable + Default>(&self, method: Method, url: Url) -> Result<Option<D>, GitErr>;
+    fn request_if_modified<D: Decodable>(&self, method: Method, url: Url, last_modified: Option<String>) -> Result<PollResponse<D>, GitErr>;
+    fn request_rate_limited<D: Decodable>(&self, method: Method, url: Url) -> Result<RateLimitedResponse<D>, GitErr>;
+
+    fn request_with_query<D: Decodable, E: Encodable>(&self, method: Method, url: Url, query: E) -> Result<D, GitErr> {
+      url_with_query(url, &query)
+        .and_then(|url| self.request_without_payload(method, url))
+    }
   }
 
   impl<S:Scheme + Any> GithubClient<S> where S::Err: 'static {
     pub fn new(token: Option<Authorization<S>>) -> GithubClient<S> {
-      GithubClient { client: Client::new(), token: token }
+      // Downloads redirect to pre-signed storage urls, which reject requests
+      // carrying our authorization, so the downloader leaves redirects to
+      // request_to_writer.
+      let mut downloader = Client::new();
+      downloader.set_redirect_policy(RedirectPolicy::FollowNone);
+      GithubClient { client: Client::new(), downloader: downloader, token: token }
     }
 
     fn request(&self, method: Method, url: Url, body: Option<Body>) -> Result<Response, GitErr> {
@@ -64,19 +168,25 @@ pub mod github_client {
     }
 
     fn build_common_request<'a, U: IntoUrl>(&self, request: RequestBuilder<'a, U>, body: &'a str) -> Result<Response, GitErr> {
+      self
+        .common_headers(request, github_json())
+        .body(body)
+        .send()
+        .map_err(net_err_to_git_err)
+    }
+
+    fn common_headers<'a, U: IntoUrl>(&self, request: RequestBuilder<'a, U>, accept: Mime) -> RequestBuilder<'a, U> {
       let common_request =
         request
-          .header(Accept(vec![qitem(Mime(TopLevel::Application, SubLevel::Ext("vnd.github.v3+json".to_owned()), vec![]))]))
+          .header(Accept(vec![qitem(accept)]))
           .header(UserAgent("CatalystBot".to_owned()))
           .header(Connection::close());
 
       let token = self.token.clone();
-      let auth_request = match token {
+      match token {
         Some(authorization) => common_request.header(authorization),
         None => common_request
-      };
-
-      auth_request.body(body).send().map_err(net_err_to_git_err)
+      }
     }
   }
 
@@ -95,5 +205,193 @@ pub mod github_client {
         .and_then(deserialize)
     }
 
+    fn request_for_status(&self, method: Method, url: Url) -> Result<StatusCode, GitErr> {
+      self
+        .request(method, url, None)
+        .map(|response| response.status)
+    }
+
+    fn request_with_payload_for_status<E: Encodable>(&self, method: Method, url: Url, body: E) -> Result<StatusCode, GitErr> {
+      json::encode(&body)
+        .map_err(encode_err_to_git_err)
+        .and_then(|query| self.request(method, url, Some(query)))
+        .map(|response| response.status)
+    }
+
+    fn request_with_raw_payload<D: Decodable, R: Read>(&self, method: Method, url: Url, content_type: Mime, body: &mut R, length: u64) -> Result<D, GitErr> {
+      let initial_request = self.client.request(method, &url);
+      self
+        .common_headers(initial_request, github_json())
+        .header(ContentType(content_type))
+        .body(RequestBody::SizedBody(body, length))
+        .send()
+        .map_err(net_err_to_git_err)
+        .and_then(deserialize)
+    }
+
+    fn request_to_writer<W: Write>(&self, method: Method, url: Url, accept: Mime, out: &mut W) -> Result<u64, GitErr> {
+      let initial_request = self.downloader.request(method, &url);
+      let mut response = try!(self.common_headers(initial_request, accept).send().map_err(net_err_to_git_err));
+
+      let mut redirects = 0;
+      while response.status.is_redirection() {
+        if redirects == MAX_DOWNLOAD_REDIRECTS {
+          return Err(GitErr::NetworkErr("too many redirects downloading ".to_owned() + &url));
+        }
+        let location = try!(
+          response.headers.get::<Location>()
+            .map(|location| location.0.clone())
+            .ok_or(GitErr::UnexpectedStatus(response.status.to_string())));
+        response = try!(
+          self.downloader
+            .get(&location)
+            .header(UserAgent("CatalystBot".to_owned()))
+            .send()
+            .map_err(net_err_to_git_err));
+        redirects += 1;
+      }
+
+      if !response.status.is_success() {
+        return Err(GitErr::UnexpectedStatus(response.status.to_string()));
+      }
+
+      io::copy(&mut response, out).map_err(io_err_to_git_err)
+    }
+
+    // Github answers 202 Accepted while it computes a result in the background,
+    // and 204 No Content when there is nothing to compute, such as for an
+    // empty repository
+    fn request_if_ready<D: Decodable + Default>(&self, method: Method, url: Url) -> Result<Option<D>, GitErr> {
+      self
+        .request(method, url, None)
+        .and_then(|response| match response.status {
+          StatusCode::Accepted => Ok(None),
+          StatusCode::NoContent => Ok(Some(D::default())),
+          _ => deserialize(response).map(Some)
+        })
+    }
+
+    fn request_if_modified<D: Decodable>(&self, method: Method, url: Url, last_modified: Option<String>) -> Result<PollResponse<D>, GitErr> {
+      let mut headers = Headers::new();
+      if let Some(last_modified) = last_modified {
+        headers.set_raw("If-Modified-Since", vec![last_modified.into_bytes()]);
+      }
+
+      let initial_request = self.client.request(method, &url).headers(headers);
+      let response = try!(self.common_headers(initial_request, github_json()).send().map_err(net_err_to_git_err));
+
+      let poll_interval = raw_header(&response, "X-Poll-Interval")
+        .and_then(|seconds| seconds.parse().ok())
+        .map(Duration::from_secs);
+      let last_modified = raw_header(&response, "Last-Modified");
+      let content = match response.status {
+        StatusCode::NotModified => None,
+        _ => Some(try!(deserialize(response)))
+      };
+
+      Ok(PollResponse { content: content, poll_interval: poll_interval, last_modified: last_modified })
+    }
+
+    // Github refuses requests over the rate limit with 403 Forbidden, or with
+    // 429 Too Many Requests for its secondary limits
+    fn request_rate_limited<D: Decodable>(&self, method: Method, url: Url) -> Result<RateLimitedResponse<D>, GitErr> {
+      let response = try!(self.request(method, url, None));
+
+      let remaining = raw_header(&response, "X-RateLimit-Remaining").and_then(|remaining| remaining.parse().ok());
+      let reset = raw_header(&response, "X-RateLimit-Reset").and_then(|reset| reset.parse().ok());
+      let retry_after = raw_header(&response, "Retry-After")
+        .and_then(|seconds| seconds.parse().ok())
+        .map(Duration::from_secs);
+      let limited = match response.status {
+        StatusCode::TooManyRequests => true,
+        StatusCode::Forbidden => remaining == Some(0) || retry_after.is_some(),
+        _ => false
+      };
+      let content = if limited { None } else { Some(try!(deserialize(response))) };
+
+      Ok(RateLimitedResponse { content: content, remaining: remaining, reset: reset, retry_after: retry_after })
+    }
+  }
+
+  #[cfg(test)]
+  mod tests {
+    use expectest::core::expect;
+    use expectest::matchers::be_equal_to;
+    use hyper::status::StatusCode;
+
+    use rustc_serialize::{
+      Encodable,
+      Encoder,
+    };
+
+    use types::{GitErr, DeletedStatus};
+
+    use super::{url_with_query, deleted_status};
+
+    #[derive(RustcEncodable)]
+    struct TestQuery {
+      state: Option<String>,
+      labels: Option<Vec<String>>,
+      per_page: Option<u32>
+    }
+
+    struct ReservedKeyQuery;
+
+    impl Encodable for ReservedKeyQuery {
+      fn encode<S: Encoder>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.emit_struct("ReservedKeyQuery", 1, |s| {
+          s.emit_struct_field("filter[name]", 0, |s| "x".encode(s))
+        })
+      }
+    }
+
+    #[test]
+    fn it_skips_none_fields() {
+      let query = TestQuery { state: Some("open".to_owned()), labels: None, per_page: None };
+      let expected = "https://api.github.com/issues?state=open";
+      expect!(url_with_query("https://api.github.com/issues".to_owned(), &query).unwrap()).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn it_joins_arrays() {
+      let query = TestQuery { state: None, labels: Some(vec!["bug".to_owned(), "ui".to_owned()]), per_page: Some(50) };
+      let expected = "https://api.github.com/issues?labels=bug%2Cui&per_page=50";
+      expect!(url_with_query("https://api.github.com/issues".to_owned(), &query).unwrap()).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn it_encodes_reserved_characters() {
+      let query = TestQuery { state: Some("a b&c=d/#".to_owned()), labels: None, per_page: None };
+      let expected = "https://api.github.com/issues?state=a%20b%26c%3Dd%2F%23";
+      expect!(url_with_query("https://api.github.com/issues".to_owned(), &query).unwrap()).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn it_encodes_reserved_characters_in_keys() {
+      let expected = "https://api.github.com/issues?filter%5Bname%5D=x";
+      expect!(url_with_query("https://api.github.com/issues".to_owned(), &ReservedKeyQuery).unwrap()).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn it_appends_to_an_existing_query() {
+      let query = TestQuery { state: Some("open".to_owned()), labels: None, per_page: None };
+      let expected = "https://api.github.com/issues?page=2&state=open";
+      expect!(url_with_query("https://api.github.com/issues?page=2".to_owned(), &query).unwrap()).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn it_leaves_the_url_unchanged_for_an_empty_query() {
+      let query = TestQuery { state: None, labels: None, per_page: None };
+      let expected = "https://api.github.com/issues";
+      expect!(url_with_query("https://api.github.com/issues".to_owned(), &query).unwrap()).to(be_equal_to(expected));
+    }
+
+    #[test]
+    fn it_maps_deleted_statuses() {
+      expect!(deleted_status(StatusCode::NoContent)).to(be_equal_to(Ok(DeletedStatus::Deleted)));
+      expect!(deleted_status(StatusCode::Forbidden)).to(be_equal_to(Ok(DeletedStatus::NotDeleted)));
+      expect!(deleted_status(StatusCode::NotFound)).to(be_equal_to(Ok(DeletedStatus::NotDeleted)));
+      expect!(deleted_status(StatusCode::Conflict)).to(be_equal_to(Err(GitErr::UnexpectedStatus(StatusCode::Conflict.to_string()))));
+    }
   }
 }
@@ -1,6 +1,7 @@
 pub mod url_builders;
 
 use hyper::method::Method;
+use hyper::status::StatusCode;
 
 use github_client::{SimpleClient};
 
@@ -34,7 +35,7 @@ impl<C: SimpleClient> IssueCommenter for C {
   fn list_in_issue(&self, repo: Repository, issue_id: IssueId, query: Option<ListIssueCommentsQuery>) -> Result<Vec<IssueComment>, GitErr> {
     let url = url_builders::issue_comments(&repo, &issue_id);
     match query {
-      Some(query) => self.request_with_payload(Method::Get, url, query),
+      Some(query) => self.request_with_query(Method::Get, url, query),
       None => self.request_without_payload(Method::Get, url)
     }
   }
@@ -42,7 +43,7 @@ impl<C: SimpleClient> IssueCommenter for C {
   fn list_in_repo(&self, repo: Repository, query: Option<ListRepoCommentsQuery>) -> Result<Vec<IssueComment>, GitErr> {
     let url = url_builders::issue_comments_for_repo(&repo);
     match query {
-      Some(query) => self.request_with_payload(Method::Get, url, query),
+      Some(query) => self.request_with_query(Method::Get, url, query),
       None => self.request_without_payload(Method::Get, url)
     }
   }
@@ -59,12 +60,45 @@ impl<C: SimpleClient> IssueCommenter for C {
 
   fn edit_comment(&self, repo: Repository, comment_id: CommentId, details: EditComment) -> Result<IssueComment, GitErr> {
     let url = url_builders::issue_comment_at(&repo, &comment_id);
-    self.request_with_payload(Method::Post, url, details)
+    self.request_with_payload(Method::Patch, url, details)
   }
 
-  #[allow(unused_variables)]
   fn delete_comment(&self, repo: Repository, comment_id: CommentId) -> Result<DeleteCommentStatus, GitErr> {
-    // TODO:
-    Err(GitErr::NotImplemented("IssueCommenter#delete_comment".to_owned()))
+    let url = url_builders::issue_comment_at(&repo, &comment_id);
+    self
+      .request_for_status(Method::Delete, url)
+      .and_then(|status| match status {
+        StatusCode::NoContent => Ok(DeleteCommentStatus::Deleted),
+        StatusCode::Forbidden | StatusCode::NotFound => Ok(DeleteCommentStatus::NotDeleted),
+        status => Err(GitErr::UnexpectedStatus(status.to_string()))
+      })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+  use hyper::status::StatusCode;
+
+  use test_client::{MockClient, MockResponse};
+  use types::GitErr;
+  use types::comments::DeleteCommentStatus;
+  use types::repos::Repository;
+
+  use super::IssueCommenter;
+
+  fn delete_with_status(status: StatusCode) -> Result<DeleteCommentStatus, GitErr> {
+    let client = MockClient::new(vec![MockResponse::status(status)]);
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    client.delete_comment(repo, 1)
+  }
+
+  #[test]
+  fn it_maps_delete_comment_statuses() {
+    expect!(delete_with_status(StatusCode::NoContent)).to(be_equal_to(Ok(DeleteCommentStatus::Deleted)));
+    expect!(delete_with_status(StatusCode::Forbidden)).to(be_equal_to(Ok(DeleteCommentStatus::NotDeleted)));
+    expect!(delete_with_status(StatusCode::NotFound)).to(be_equal_to(Ok(DeleteCommentStatus::NotDeleted)));
+    expect!(delete_with_status(StatusCode::Conflict)).to(be_equal_to(Err(GitErr::UnexpectedStatus(StatusCode::Conflict.to_string()))));
   }
 }
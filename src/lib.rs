@@ -11,14 +11,65 @@ pub mod types;
 
 pub mod github_client;
 mod pull_requests;
+mod audit;
+mod branch_protection;
+mod checks;
+mod collaborators;
 mod commit_comments;
+mod commits;
+mod contents;
+mod deploy_keys;
+mod deployments;
+mod file_committer;
+mod followers;
+mod forks;
+mod git_data;
 mod issue_comments;
+mod notifications;
+mod orgs;
+mod pagination;
+mod polling;
+mod reactions;
+mod releases;
 mod repos;
+mod search;
+mod stats;
+mod statuses;
+mod teams;
+mod user_keys;
+mod users;
 
+#[cfg(test)]
+mod test_client;
+
+pub use audit::OrgAuditor;
+pub use branch_protection::BranchProtector;
+pub use checks::Checker;
+pub use collaborators::Collaborator;
 pub use commit_comments::CommitCommenter;
+pub use commits::Committer;
+pub use contents::Contenter;
+pub use deploy_keys::DeployKeyer;
+pub use deployments::Deployer;
+pub use file_committer::FileCommitter;
+pub use followers::Follower;
+pub use forks::Forker;
+pub use git_data::GitData;
 pub use issue_comments::IssueCommenter;
+pub use notifications::Notifier;
+pub use orgs::Organizer;
 pub use pull_requests::PullRequester;
+pub use reactions::Reactor;
+pub use releases::Releaser;
 pub use repos::Repoer;
+pub use search::Searcher;
+pub use stats::Statistician;
+pub use statuses::Statuser;
+pub use teams::Teamer;
+pub use user_keys::UserKeyer;
+pub use users::Userer;
 
 pub use hyper::header::Authorization;
 pub use hyper::method::Method;
+pub use hyper::mime::Mime;
+pub use hyper::status::StatusCode;
@@ -0,0 +1,263 @@
+pub mod url_builders;
+
+use std::collections::HashMap;
+use std::thread;
+use std::time::Duration;
+
+use hyper::method::Method;
+use hyper::status::StatusCode;
+
+use github_client::{
+  SimpleClient,
+  deleted_status,
+  url_with_query,
+};
+
+use types::{
+  Url,
+  GitErr,
+  GitTm,
+  PollResponse,
+  DeletedStatus,
+};
+
+use types::notifications::{
+  ThreadId,
+  Notification,
+  NotificationsQuery,
+  MarkAsRead,
+  ReadStatus,
+  ThreadSubscription,
+  SetThreadSubscription,
+};
+
+use types::repos::Repository;
+
+// Used when Github does not send an X-Poll-Interval header
+const DEFAULT_POLL_INTERVAL_SECS: u64 = 60;
+// Page size of watch_notifications when the query does not set one
+const DEFAULT_WATCH_PER_PAGE: u32 = 50;
+
+/// Listing notifications takes the Last-Modified value of the previous
+/// listing; Github answers 304 Not Modified, which does not count against
+/// the rate limit, when nothing changed since.
+pub trait Notifier {
+  fn list_notifications(&self, query: Option<NotificationsQuery>, last_modified: Option<String>) -> Result<PollResponse<Vec<Notification>>, GitErr>;
+  fn list_repo_notifications(&self, repo: Repository, query: Option<NotificationsQuery>, last_modified: Option<String>) -> Result<PollResponse<Vec<Notification>>, GitErr>;
+  fn watch_notifications<F>(&self, query: Option<NotificationsQuery>, on_notifications: F) -> Result<(), GitErr>
+    where F: FnMut(Vec<Notification>) -> bool;
+  fn mark_notifications_read(&self, details: MarkAsRead) -> Result<ReadStatus, GitErr>;
+  fn mark_repo_notifications_read(&self, repo: Repository, details: MarkAsRead) -> Result<ReadStatus, GitErr>;
+  fn get_thread(&self, thread_id: ThreadId) -> Result<Notification, GitErr>;
+  fn mark_thread_read(&self, thread_id: ThreadId) -> Result<ReadStatus, GitErr>;
+  fn get_thread_subscription(&self, thread_id: ThreadId) -> Result<ThreadSubscription, GitErr>;
+  fn set_thread_subscription(&self, thread_id: ThreadId, details: SetThreadSubscription) -> Result<ThreadSubscription, GitErr>;
+  fn delete_thread_subscription(&self, thread_id: ThreadId) -> Result<DeletedStatus, GitErr>;
+}
+
+fn list<C: SimpleClient>(client: &C, url: Url, query: Option<NotificationsQuery>, last_modified: Option<String>) -> Result<PollResponse<Vec<Notification>>, GitErr> {
+  let url = match query {
+    Some(query) => try!(url_with_query(url, &query)),
+    None => url
+  };
+  client.request_if_modified(Method::Get, url, last_modified)
+}
+
+fn read_status(status: StatusCode) -> Result<ReadStatus, GitErr> {
+  match status {
+    StatusCode::ResetContent => Ok(ReadStatus::Read),
+    StatusCode::Accepted => Ok(ReadStatus::ReadPending),
+    status => Err(GitErr::UnexpectedStatus(status.to_string()))
+  }
+}
+
+impl<C: SimpleClient> Notifier for C {
+  fn list_notifications(&self, query: Option<NotificationsQuery>, last_modified: Option<String>) -> Result<PollResponse<Vec<Notification>>, GitErr> {
+    list(self, url_builders::notifications(), query, last_modified)
+  }
+
+  fn list_repo_notifications(&self, repo: Repository, query: Option<NotificationsQuery>, last_modified: Option<String>) -> Result<PollResponse<Vec<Notification>>, GitErr> {
+    list(self, url_builders::repo_notifications(&repo), query, last_modified)
+  }
+
+  // Polls until `on_notifications` returns false, waiting as long between
+  // polls as Github asks to. Each poll reads every page of the listing, and
+  // a thread is only delivered again once it has been updated.
+  fn watch_notifications<F>(&self, query: Option<NotificationsQuery>, mut on_notifications: F) -> Result<(), GitErr>
+    where F: FnMut(Vec<Notification>) -> bool {
+    let mut query = query.unwrap_or(NotificationsQuery { all: None, participating: None, since: None, before: None, per_page: None, page: None });
+    let per_page = query.per_page.unwrap_or(DEFAULT_WATCH_PER_PAGE);
+    query.per_page = Some(per_page);
+
+    let mut last_modified = None;
+    let mut delivered: HashMap<ThreadId, GitTm> = HashMap::new();
+
+    loop {
+      query.page = Some(1);
+      let response = try!(self.list_notifications(Some(query.clone()), last_modified.clone()));
+
+      if let Some(first_page) = response.content {
+        let mut notifications = first_page;
+        let mut page_len = notifications.len();
+        while page_len == per_page as usize {
+          query.page = query.page.map(|page| page + 1);
+          let page = try!(self.list_notifications(Some(query.clone()), None)).content.unwrap_or(Vec::new());
+          page_len = page.len();
+          notifications.extend(page.into_iter());
+        }
+
+        let fresh: Vec<Notification> = notifications
+          .iter()
+          .filter(|notification| delivered.get(&notification.id) != Some(&notification.updated_at))
+          .cloned()
+          .collect();
+        // Threads no longer listed are forgotten, so this stays as small as
+        // the listing
+        delivered = notifications
+          .into_iter()
+          .map(|notification| (notification.id, notification.updated_at))
+          .collect();
+
+        if !fresh.is_empty() && !on_notifications(fresh) {
+          return Ok(());
+        }
+      }
+
+      last_modified = response.last_modified.or(last_modified);
+      thread::sleep(response.poll_interval.unwrap_or(Duration::from_secs(DEFAULT_POLL_INTERVAL_SECS)));
+    }
+  }
+
+  fn mark_notifications_read(&self, details: MarkAsRead) -> Result<ReadStatus, GitErr> {
+    let url = url_builders::notifications();
+    self
+      .request_with_payload_for_status(Method::Put, url, details)
+      .and_then(read_status)
+  }
+
+  fn mark_repo_notifications_read(&self, repo: Repository, details: MarkAsRead) -> Result<ReadStatus, GitErr> {
+    let url = url_builders::repo_notifications(&repo);
+    self
+      .request_with_payload_for_status(Method::Put, url, details)
+      .and_then(read_status)
+  }
+
+  fn get_thread(&self, thread_id: ThreadId) -> Result<Notification, GitErr> {
+    let url = url_builders::thread_at(&thread_id);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn mark_thread_read(&self, thread_id: ThreadId) -> Result<ReadStatus, GitErr> {
+    let url = url_builders::thread_at(&thread_id);
+    self
+      .request_for_status(Method::Patch, url)
+      .and_then(read_status)
+  }
+
+  fn get_thread_subscription(&self, thread_id: ThreadId) -> Result<ThreadSubscription, GitErr> {
+    let url = url_builders::thread_subscription(&thread_id);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn set_thread_subscription(&self, thread_id: ThreadId, details: SetThreadSubscription) -> Result<ThreadSubscription, GitErr> {
+    let url = url_builders::thread_subscription(&thread_id);
+    self.request_with_payload(Method::Put, url, details)
+  }
+
+  fn delete_thread_subscription(&self, thread_id: ThreadId) -> Result<DeletedStatus, GitErr> {
+    let url = url_builders::thread_subscription(&thread_id);
+    self
+      .request_for_status(Method::Delete, url)
+      .and_then(deleted_status)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+  use hyper::status::StatusCode;
+
+  use test_client::{MockClient, MockResponse};
+  use types::notifications::NotificationsQuery;
+
+  use super::Notifier;
+
+  fn notification(id: &str, updated_at: &str) -> String {
+    format!(r#"{{
+      "id": "{}",
+      "repository": {{
+        "id": 1,
+        "owner": {{
+          "login": "octocat", "id": 1, "avatar_url": "", "gravatar_id": "", "html_url": "",
+          "followers_url": "", "following_url": "", "gists_url": "", "starred_url": "",
+          "subscriptions_url": "", "organizations_url": "", "repos_url": "", "events_url": "",
+          "received_events_url": "", "site_admin": false
+        }},
+        "name": "Hello-World",
+        "full_name": "octocat/Hello-World",
+        "private": false,
+        "html_url": "https://github.com/octocat/Hello-World",
+        "url": "https://api.github.com/repos/octocat/Hello-World"
+      }},
+      "subject": {{ "title": "Greetings", "url": null, "latest_comment_url": null, "type": "Issue" }},
+      "reason": "subscribed",
+      "unread": true,
+      "updated_at": "{}",
+      "last_read_at": null,
+      "url": "https://api.github.com/notifications/threads/{}",
+      "subscription_url": "https://api.github.com/notifications/threads/{}/subscription"
+    }}"#, id, updated_at, id, id)
+  }
+
+  fn page(notifications: &[(&str, &str)]) -> MockResponse {
+    let items: Vec<String> = notifications.iter().map(|&(id, updated_at)| notification(id, updated_at)).collect();
+    MockResponse::json(&("[".to_owned() + &items.join(",") + "]"))
+      .header("X-Poll-Interval", "0")
+      .header("Last-Modified", "Thu, 25 Oct 2012 15:16:27 GMT")
+  }
+
+  fn query() -> Option<NotificationsQuery> {
+    Some(NotificationsQuery { all: None, participating: None, since: None, before: None, per_page: Some(2), page: None })
+  }
+
+  const EARLY: &'static str = "2020-01-01T00:00:00Z";
+  const LATE: &'static str = "2020-01-02T00:00:00Z";
+
+  #[test]
+  fn it_reads_every_page_of_a_poll() {
+    let client = MockClient::new(vec![
+      page(&[("1", EARLY), ("2", EARLY)]),
+      page(&[("3", EARLY)]),
+    ]);
+    let mut delivered = Vec::new();
+
+    client.watch_notifications(query(), |notifications| {
+      delivered.extend(notifications.into_iter().map(|notification| notification.id));
+      false
+    }).unwrap();
+
+    expect!(delivered).to(be_equal_to(vec!["1".to_owned(), "2".to_owned(), "3".to_owned()]));
+    let requests = client.requests.borrow();
+    expect!(requests[1].url.clone()).to(be_equal_to("https://api.github.com/notifications?page=2&per_page=2".to_owned()));
+  }
+
+  #[test]
+  fn it_only_redelivers_updated_threads() {
+    let client = MockClient::new(vec![
+      page(&[("1", EARLY)]),
+      MockResponse::status(StatusCode::NotModified).header("X-Poll-Interval", "0"),
+      page(&[("1", EARLY), ("2", EARLY)]),
+      page(&[]),
+      page(&[("1", LATE)]),
+    ]);
+    let mut deliveries = Vec::new();
+
+    client.watch_notifications(query(), |notifications| {
+      deliveries.push(notifications.into_iter().map(|notification| notification.id).collect::<Vec<_>>());
+      deliveries.len() < 3
+    }).unwrap();
+
+    expect!(deliveries).to(be_equal_to(vec![vec!["1".to_owned()], vec!["2".to_owned()], vec!["1".to_owned()]]));
+  }
+}
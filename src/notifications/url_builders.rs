@@ -0,0 +1,61 @@
+use types::Url;
+use types::notifications::ThreadId;
+use types::repos::Repository;
+
+use repos::url_builders::repo_at;
+
+pub fn notifications() -> Url {
+  "https://api.github.com/notifications".to_owned()
+}
+
+pub fn repo_notifications(repo: &Repository) -> Url {
+  repo_at(repo) + "/notifications"
+}
+
+pub fn thread_at(thread_id: &ThreadId) -> Url {
+  notifications() + "/threads/" + thread_id
+}
+
+pub fn thread_subscription(thread_id: &ThreadId) -> Url {
+  thread_at(thread_id) + "/subscription"
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+
+  use types::repos::Repository;
+
+  use super::{
+    notifications,
+    repo_notifications,
+    thread_at,
+    thread_subscription,
+  };
+
+  #[test]
+  fn it_builds_notifications() {
+    let expected = "https://api.github.com/notifications";
+    expect!(notifications()).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_repo_notifications() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/notifications";
+    expect!(repo_notifications(&repo)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_thread_at() {
+    let expected = "https://api.github.com/notifications/threads/42";
+    expect!(thread_at(&("42".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_thread_subscription() {
+    let expected = "https://api.github.com/notifications/threads/42/subscription";
+    expect!(thread_subscription(&("42".to_owned()))).to(be_equal_to(expected));
+  }
+}
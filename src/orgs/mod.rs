@@ -0,0 +1,178 @@
+pub mod url_builders;
+
+use hyper::method::Method;
+use hyper::status::StatusCode;
+
+use github_client::{
+  SimpleClient,
+  deleted_status,
+};
+
+use types::{
+  GitErr,
+  OrganizationName,
+  DeletedStatus,
+};
+
+use types::orgs::{
+  OrgSummary,
+  FullOrganization,
+  EditOrganization,
+  MembersQuery,
+  OutsideCollaboratorsQuery,
+  OrgMembership,
+  SetOrgMembership,
+  EditOwnOrgMembership,
+  MembershipStatus,
+  ConvertedStatus,
+  OrgInvitationId,
+  OrgInvitation,
+  CreateOrgInvitation,
+};
+
+use types::users::{
+  UserName,
+  GithubUser,
+};
+
+pub trait Organizer {
+  fn get_org(&self, org: OrganizationName) -> Result<FullOrganization, GitErr>;
+  fn edit_org(&self, org: OrganizationName, details: EditOrganization) -> Result<FullOrganization, GitErr>;
+  fn list_own_orgs(&self) -> Result<Vec<OrgSummary>, GitErr>;
+  fn list_user_orgs(&self, user: UserName) -> Result<Vec<OrgSummary>, GitErr>;
+  fn list_members(&self, org: OrganizationName, query: Option<MembersQuery>) -> Result<Vec<GithubUser>, GitErr>;
+  fn is_member(&self, org: OrganizationName, user: UserName) -> Result<MembershipStatus, GitErr>;
+  fn remove_member(&self, org: OrganizationName, user: UserName) -> Result<DeletedStatus, GitErr>;
+  fn get_membership(&self, org: OrganizationName, user: UserName) -> Result<OrgMembership, GitErr>;
+  fn set_membership(&self, org: OrganizationName, user: UserName, details: SetOrgMembership) -> Result<OrgMembership, GitErr>;
+  fn remove_membership(&self, org: OrganizationName, user: UserName) -> Result<DeletedStatus, GitErr>;
+  fn list_own_memberships(&self) -> Result<Vec<OrgMembership>, GitErr>;
+  fn edit_own_membership(&self, org: OrganizationName, details: EditOwnOrgMembership) -> Result<OrgMembership, GitErr>;
+  fn list_outside_collaborators(&self, org: OrganizationName, query: Option<OutsideCollaboratorsQuery>) -> Result<Vec<GithubUser>, GitErr>;
+  fn convert_to_outside_collaborator(&self, org: OrganizationName, user: UserName) -> Result<ConvertedStatus, GitErr>;
+  fn remove_outside_collaborator(&self, org: OrganizationName, user: UserName) -> Result<DeletedStatus, GitErr>;
+  fn list_pending_invitations(&self, org: OrganizationName) -> Result<Vec<OrgInvitation>, GitErr>;
+  fn create_org_invitation(&self, org: OrganizationName, details: CreateOrgInvitation) -> Result<OrgInvitation, GitErr>;
+  fn cancel_org_invitation(&self, org: OrganizationName, invitation_id: OrgInvitationId) -> Result<DeletedStatus, GitErr>;
+}
+
+impl<C: SimpleClient> Organizer for C {
+  fn get_org(&self, org: OrganizationName) -> Result<FullOrganization, GitErr> {
+    let url = url_builders::org_at(&org);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn edit_org(&self, org: OrganizationName, details: EditOrganization) -> Result<FullOrganization, GitErr> {
+    let url = url_builders::org_at(&org);
+    self.request_with_payload(Method::Patch, url, details)
+  }
+
+  fn list_own_orgs(&self) -> Result<Vec<OrgSummary>, GitErr> {
+    let url = url_builders::own_orgs();
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn list_user_orgs(&self, user: UserName) -> Result<Vec<OrgSummary>, GitErr> {
+    let url = url_builders::user_orgs(&user);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn list_members(&self, org: OrganizationName, query: Option<MembersQuery>) -> Result<Vec<GithubUser>, GitErr> {
+    let url = url_builders::members_at(&org);
+    match query {
+      Some(query) => self.request_with_query(Method::Get, url, query),
+      None => self.request_without_payload(Method::Get, url)
+    }
+  }
+
+  // Requesters outside the organization are redirected to the public
+  // membership check. The client follows the redirect, so for them only
+  // public members are reported as members.
+  fn is_member(&self, org: OrganizationName, user: UserName) -> Result<MembershipStatus, GitErr> {
+    let url = url_builders::member_at(&org, &user);
+    self
+      .request_for_status(Method::Get, url)
+      .and_then(|status| match status {
+        StatusCode::NoContent => Ok(MembershipStatus::Member),
+        StatusCode::NotFound => Ok(MembershipStatus::NotMember),
+        status => Err(GitErr::UnexpectedStatus(status.to_string()))
+      })
+  }
+
+  fn remove_member(&self, org: OrganizationName, user: UserName) -> Result<DeletedStatus, GitErr> {
+    let url = url_builders::member_at(&org, &user);
+    self
+      .request_for_status(Method::Delete, url)
+      .and_then(deleted_status)
+  }
+
+  fn get_membership(&self, org: OrganizationName, user: UserName) -> Result<OrgMembership, GitErr> {
+    let url = url_builders::membership_at(&org, &user);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn set_membership(&self, org: OrganizationName, user: UserName, details: SetOrgMembership) -> Result<OrgMembership, GitErr> {
+    let url = url_builders::membership_at(&org, &user);
+    self.request_with_payload(Method::Put, url, details)
+  }
+
+  fn remove_membership(&self, org: OrganizationName, user: UserName) -> Result<DeletedStatus, GitErr> {
+    let url = url_builders::membership_at(&org, &user);
+    self
+      .request_for_status(Method::Delete, url)
+      .and_then(deleted_status)
+  }
+
+  fn list_own_memberships(&self) -> Result<Vec<OrgMembership>, GitErr> {
+    let url = url_builders::own_memberships();
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn edit_own_membership(&self, org: OrganizationName, details: EditOwnOrgMembership) -> Result<OrgMembership, GitErr> {
+    let url = url_builders::own_membership_at(&org);
+    self.request_with_payload(Method::Patch, url, details)
+  }
+
+  fn list_outside_collaborators(&self, org: OrganizationName, query: Option<OutsideCollaboratorsQuery>) -> Result<Vec<GithubUser>, GitErr> {
+    let url = url_builders::outside_collaborators_at(&org);
+    match query {
+      Some(query) => self.request_with_query(Method::Get, url, query),
+      None => self.request_without_payload(Method::Get, url)
+    }
+  }
+
+  fn convert_to_outside_collaborator(&self, org: OrganizationName, user: UserName) -> Result<ConvertedStatus, GitErr> {
+    let url = url_builders::outside_collaborator_at(&org, &user);
+    self
+      .request_for_status(Method::Put, url)
+      .and_then(|status| match status {
+        StatusCode::Accepted | StatusCode::NoContent => Ok(ConvertedStatus::Converted),
+        StatusCode::Forbidden | StatusCode::NotFound => Ok(ConvertedStatus::NotConverted),
+        status => Err(GitErr::UnexpectedStatus(status.to_string()))
+      })
+  }
+
+  fn remove_outside_collaborator(&self, org: OrganizationName, user: UserName) -> Result<DeletedStatus, GitErr> {
+    let url = url_builders::outside_collaborator_at(&org, &user);
+    self
+      .request_for_status(Method::Delete, url)
+      .and_then(deleted_status)
+  }
+
+  fn list_pending_invitations(&self, org: OrganizationName) -> Result<Vec<OrgInvitation>, GitErr> {
+    let url = url_builders::org_invitations(&org);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn create_org_invitation(&self, org: OrganizationName, details: CreateOrgInvitation) -> Result<OrgInvitation, GitErr> {
+    let url = url_builders::org_invitations(&org);
+    self.request_with_payload(Method::Post, url, details)
+  }
+
+  fn cancel_org_invitation(&self, org: OrganizationName, invitation_id: OrgInvitationId) -> Result<DeletedStatus, GitErr> {
+    let url = url_builders::org_invitation_at(&org, &invitation_id);
+    self
+      .request_for_status(Method::Delete, url)
+      .and_then(deleted_status)
+  }
+}
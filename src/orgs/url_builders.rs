@@ -0,0 +1,152 @@
+use types::{
+  Url,
+  OrganizationName,
+};
+use types::orgs::OrgInvitationId;
+use types::users::UserName;
+
+use users::url_builders::{
+  authenticated_user,
+  user_at,
+};
+
+pub fn org_at(org: &OrganizationName) -> Url {
+  "https://api.github.com/orgs/".to_owned() + org
+}
+
+pub fn own_orgs() -> Url {
+  authenticated_user() + "/orgs"
+}
+
+pub fn user_orgs(user: &UserName) -> Url {
+  user_at(user) + "/orgs"
+}
+
+pub fn members_at(org: &OrganizationName) -> Url {
+  org_at(org) + "/members"
+}
+
+pub fn member_at(org: &OrganizationName, user: &UserName) -> Url {
+  members_at(org) + "/" + user
+}
+
+pub fn membership_at(org: &OrganizationName, user: &UserName) -> Url {
+  org_at(org) + "/memberships/" + user
+}
+
+pub fn own_memberships() -> Url {
+  authenticated_user() + "/memberships/orgs"
+}
+
+pub fn own_membership_at(org: &OrganizationName) -> Url {
+  own_memberships() + "/" + org
+}
+
+pub fn outside_collaborators_at(org: &OrganizationName) -> Url {
+  org_at(org) + "/outside_collaborators"
+}
+
+pub fn outside_collaborator_at(org: &OrganizationName, user: &UserName) -> Url {
+  outside_collaborators_at(org) + "/" + user
+}
+
+pub fn org_invitations(org: &OrganizationName) -> Url {
+  org_at(org) + "/invitations"
+}
+
+pub fn org_invitation_at(org: &OrganizationName, invitation_id: &OrgInvitationId) -> Url {
+  org_invitations(org) + "/" + &invitation_id.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+
+  use super::{
+    org_at,
+    own_orgs,
+    user_orgs,
+    members_at,
+    member_at,
+    membership_at,
+    own_memberships,
+    own_membership_at,
+    outside_collaborators_at,
+    outside_collaborator_at,
+    org_invitations,
+    org_invitation_at,
+  };
+
+  #[test]
+  fn it_builds_org_at() {
+    let expected = "https://api.github.com/orgs/test_org";
+    expect!(org_at(&("test_org".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_own_orgs() {
+    let expected = "https://api.github.com/user/orgs";
+    expect!(own_orgs()).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_user_orgs() {
+    let expected = "https://api.github.com/users/test_user/orgs";
+    expect!(user_orgs(&("test_user".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_members_at() {
+    let expected = "https://api.github.com/orgs/test_org/members";
+    expect!(members_at(&("test_org".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_member_at() {
+    let expected = "https://api.github.com/orgs/test_org/members/test_user";
+    expect!(member_at(&("test_org".to_owned()), &("test_user".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_membership_at() {
+    let expected = "https://api.github.com/orgs/test_org/memberships/test_user";
+    expect!(membership_at(&("test_org".to_owned()), &("test_user".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_own_memberships() {
+    let expected = "https://api.github.com/user/memberships/orgs";
+    expect!(own_memberships()).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_own_membership_at() {
+    let expected = "https://api.github.com/user/memberships/orgs/test_org";
+    expect!(own_membership_at(&("test_org".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_outside_collaborators_at() {
+    let expected = "https://api.github.com/orgs/test_org/outside_collaborators";
+    expect!(outside_collaborators_at(&("test_org".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_outside_collaborator_at() {
+    let expected = "https://api.github.com/orgs/test_org/outside_collaborators/test_user";
+    expect!(outside_collaborator_at(&("test_org".to_owned()), &("test_user".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_org_invitations() {
+    let expected = "https://api.github.com/orgs/test_org/invitations";
+    expect!(org_invitations(&("test_org".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_org_invitation_at() {
+    let expected = "https://api.github.com/orgs/test_org/invitations/42";
+    expect!(org_invitation_at(&("test_org".to_owned()), &42)).to(be_equal_to(expected));
+  }
+}
@@ -0,0 +1,26 @@
+use types::{
+  GitErr,
+  PageQuery,
+};
+
+pub const MAX_PER_PAGE: u32 = 100;
+
+/// Fetches consecutive pages of MAX_PER_PAGE items until a short page is
+/// returned, concatenating the results.
+pub fn collect_pages<T, F>(mut fetch_page: F) -> Result<Vec<T>, GitErr>
+  where F: FnMut(PageQuery) -> Result<Vec<T>, GitErr> {
+  let mut items = Vec::new();
+  let mut page = 1;
+
+  loop {
+    let query = PageQuery { per_page: Some(MAX_PER_PAGE), page: Some(page) };
+    let page_items = try!(fetch_page(query));
+    let page_len = page_items.len() as u32;
+    items.extend(page_items.into_iter());
+
+    if page_len < MAX_PER_PAGE {
+      return Ok(items);
+    }
+    page += 1;
+  }
+}
@@ -0,0 +1,110 @@
+use std::cmp;
+use std::thread;
+use std::time::Duration;
+
+use types::{
+  GitErr,
+  PollOptions,
+};
+
+// Zero intervals are raised to this, so that the wait always adds up to the
+// timeout
+const MIN_POLL_INTERVAL_MILLIS: u64 = 1;
+
+/// Calls `attempt` until it yields a value, sleeping between calls. The sleep
+/// starts at `initial_interval` and doubles up to `max_interval`; once the
+/// total time slept would exceed `timeout` a TimedOut error is returned.
+pub fn poll_until_ready<T, F>(options: &PollOptions, description: &str, mut attempt: F) -> Result<T, GitErr>
+  where F: FnMut() -> Result<Option<T>, GitErr> {
+  let min_interval = Duration::from_millis(MIN_POLL_INTERVAL_MILLIS);
+  let mut interval = cmp::max(options.initial_interval, min_interval);
+  let max_interval = cmp::max(options.max_interval, interval);
+  let mut waited = Duration::new(0, 0);
+
+  loop {
+    match try!(attempt()) {
+      Some(value) => return Ok(value),
+      None => {
+        if waited + interval > options.timeout {
+          return Err(GitErr::TimedOut(description.to_owned()));
+        }
+        thread::sleep(interval);
+        waited = waited + interval;
+        interval = cmp::min(interval * 2, max_interval);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::time::Duration;
+
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+
+  use types::{
+    GitErr,
+    PollOptions,
+  };
+
+  use super::poll_until_ready;
+
+  fn options(timeout: u64, initial_interval: u64, max_interval: u64) -> PollOptions {
+    PollOptions {
+      timeout: Duration::from_millis(timeout),
+      initial_interval: Duration::from_millis(initial_interval),
+      max_interval: Duration::from_millis(max_interval)
+    }
+  }
+
+  // Polls something that is never ready, returning the number of attempts
+  fn attempts_until_timeout(options: PollOptions) -> u32 {
+    let mut attempts = 0;
+    let result: Result<(), GitErr> = poll_until_ready(&options, "test", || {
+      attempts += 1;
+      Ok(None)
+    });
+    expect!(result).to(be_equal_to(Err(GitErr::TimedOut("test".to_owned()))));
+    attempts
+  }
+
+  #[test]
+  fn it_returns_the_value_once_ready() {
+    let mut attempts = 0;
+    let result = poll_until_ready(&options(1000, 1, 1), "test", || {
+      attempts += 1;
+      Ok(if attempts == 3 { Some(attempts) } else { None })
+    });
+    expect!(result).to(be_equal_to(Ok(3)));
+  }
+
+  #[test]
+  fn it_returns_errors_without_retrying() {
+    let mut attempts = 0;
+    let result: Result<(), GitErr> = poll_until_ready(&options(1000, 1, 1), "test", || {
+      attempts += 1;
+      Err(GitErr::NetworkErr("reset".to_owned()))
+    });
+    expect!(result).to(be_equal_to(Err(GitErr::NetworkErr("reset".to_owned()))));
+    expect!(attempts).to(be_equal_to(1));
+  }
+
+  #[test]
+  fn it_doubles_the_interval_between_attempts() {
+    // Sleeps 10, 20 and 40ms, then the next 40ms would pass the timeout
+    expect!(attempts_until_timeout(options(70, 10, 40))).to(be_equal_to(4));
+  }
+
+  #[test]
+  fn it_caps_the_interval() {
+    // Sleeps 10, 10 and 10ms, then the next 10ms would pass the timeout
+    expect!(attempts_until_timeout(options(30, 10, 10))).to(be_equal_to(4));
+  }
+
+  #[test]
+  fn it_times_out_with_zero_intervals() {
+    // Sleeps 1, 1 and 1ms, then the next 1ms would pass the timeout
+    expect!(attempts_until_timeout(options(3, 0, 0))).to(be_equal_to(4));
+  }
+}
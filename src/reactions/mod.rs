@@ -0,0 +1,116 @@
+pub mod url_builders;
+
+use hyper::method::Method;
+
+use github_client::{
+  SimpleClient,
+  deleted_status,
+};
+
+use types::{
+  GitErr,
+  IssueId,
+  Url,
+  DeletedStatus,
+};
+
+use types::comments::CommentId;
+
+use types::reactions::{
+  ReactionId,
+  Reaction,
+  ReactionQuery,
+  CreateReaction,
+};
+
+use types::repos::Repository;
+
+pub trait Reactor {
+  fn list_issue_reactions(&self, repo: Repository, issue_id: IssueId, query: Option<ReactionQuery>) -> Result<Vec<Reaction>, GitErr>;
+  fn create_issue_reaction(&self, repo: Repository, issue_id: IssueId, details: CreateReaction) -> Result<Reaction, GitErr>;
+  fn delete_issue_reaction(&self, repo: Repository, issue_id: IssueId, reaction_id: ReactionId) -> Result<DeletedStatus, GitErr>;
+  fn list_issue_comment_reactions(&self, repo: Repository, comment_id: CommentId, query: Option<ReactionQuery>) -> Result<Vec<Reaction>, GitErr>;
+  fn create_issue_comment_reaction(&self, repo: Repository, comment_id: CommentId, details: CreateReaction) -> Result<Reaction, GitErr>;
+  fn delete_issue_comment_reaction(&self, repo: Repository, comment_id: CommentId, reaction_id: ReactionId) -> Result<DeletedStatus, GitErr>;
+  fn list_pull_request_comment_reactions(&self, repo: Repository, comment_id: CommentId, query: Option<ReactionQuery>) -> Result<Vec<Reaction>, GitErr>;
+  fn create_pull_request_comment_reaction(&self, repo: Repository, comment_id: CommentId, details: CreateReaction) -> Result<Reaction, GitErr>;
+  fn delete_pull_request_comment_reaction(&self, repo: Repository, comment_id: CommentId, reaction_id: ReactionId) -> Result<DeletedStatus, GitErr>;
+  fn list_commit_comment_reactions(&self, repo: Repository, comment_id: CommentId, query: Option<ReactionQuery>) -> Result<Vec<Reaction>, GitErr>;
+  fn create_commit_comment_reaction(&self, repo: Repository, comment_id: CommentId, details: CreateReaction) -> Result<Reaction, GitErr>;
+  fn delete_commit_comment_reaction(&self, repo: Repository, comment_id: CommentId, reaction_id: ReactionId) -> Result<DeletedStatus, GitErr>;
+}
+
+fn list_reactions<C: SimpleClient>(client: &C, url: Url, query: Option<ReactionQuery>) -> Result<Vec<Reaction>, GitErr> {
+  match query {
+    Some(query) => client.request_with_query(Method::Get, url, query),
+    None => client.request_without_payload(Method::Get, url)
+  }
+}
+
+fn delete_reaction<C: SimpleClient>(client: &C, url: Url) -> Result<DeletedStatus, GitErr> {
+  client
+    .request_for_status(Method::Delete, url)
+    .and_then(deleted_status)
+}
+
+impl<C: SimpleClient> Reactor for C {
+  fn list_issue_reactions(&self, repo: Repository, issue_id: IssueId, query: Option<ReactionQuery>) -> Result<Vec<Reaction>, GitErr> {
+    let url = url_builders::issue_reactions(&repo, &issue_id);
+    list_reactions(self, url, query)
+  }
+
+  fn create_issue_reaction(&self, repo: Repository, issue_id: IssueId, details: CreateReaction) -> Result<Reaction, GitErr> {
+    let url = url_builders::issue_reactions(&repo, &issue_id);
+    self.request_with_payload(Method::Post, url, details)
+  }
+
+  fn delete_issue_reaction(&self, repo: Repository, issue_id: IssueId, reaction_id: ReactionId) -> Result<DeletedStatus, GitErr> {
+    let url = url_builders::issue_reaction_at(&repo, &issue_id, &reaction_id);
+    delete_reaction(self, url)
+  }
+
+  fn list_issue_comment_reactions(&self, repo: Repository, comment_id: CommentId, query: Option<ReactionQuery>) -> Result<Vec<Reaction>, GitErr> {
+    let url = url_builders::issue_comment_reactions(&repo, &comment_id);
+    list_reactions(self, url, query)
+  }
+
+  fn create_issue_comment_reaction(&self, repo: Repository, comment_id: CommentId, details: CreateReaction) -> Result<Reaction, GitErr> {
+    let url = url_builders::issue_comment_reactions(&repo, &comment_id);
+    self.request_with_payload(Method::Post, url, details)
+  }
+
+  fn delete_issue_comment_reaction(&self, repo: Repository, comment_id: CommentId, reaction_id: ReactionId) -> Result<DeletedStatus, GitErr> {
+    let url = url_builders::issue_comment_reaction_at(&repo, &comment_id, &reaction_id);
+    delete_reaction(self, url)
+  }
+
+  fn list_pull_request_comment_reactions(&self, repo: Repository, comment_id: CommentId, query: Option<ReactionQuery>) -> Result<Vec<Reaction>, GitErr> {
+    let url = url_builders::pull_request_comment_reactions(&repo, &comment_id);
+    list_reactions(self, url, query)
+  }
+
+  fn create_pull_request_comment_reaction(&self, repo: Repository, comment_id: CommentId, details: CreateReaction) -> Result<Reaction, GitErr> {
+    let url = url_builders::pull_request_comment_reactions(&repo, &comment_id);
+    self.request_with_payload(Method::Post, url, details)
+  }
+
+  fn delete_pull_request_comment_reaction(&self, repo: Repository, comment_id: CommentId, reaction_id: ReactionId) -> Result<DeletedStatus, GitErr> {
+    let url = url_builders::pull_request_comment_reaction_at(&repo, &comment_id, &reaction_id);
+    delete_reaction(self, url)
+  }
+
+  fn list_commit_comment_reactions(&self, repo: Repository, comment_id: CommentId, query: Option<ReactionQuery>) -> Result<Vec<Reaction>, GitErr> {
+    let url = url_builders::commit_comment_reactions(&repo, &comment_id);
+    list_reactions(self, url, query)
+  }
+
+  fn create_commit_comment_reaction(&self, repo: Repository, comment_id: CommentId, details: CreateReaction) -> Result<Reaction, GitErr> {
+    let url = url_builders::commit_comment_reactions(&repo, &comment_id);
+    self.request_with_payload(Method::Post, url, details)
+  }
+
+  fn delete_commit_comment_reaction(&self, repo: Repository, comment_id: CommentId, reaction_id: ReactionId) -> Result<DeletedStatus, GitErr> {
+    let url = url_builders::commit_comment_reaction_at(&repo, &comment_id, &reaction_id);
+    delete_reaction(self, url)
+  }
+}
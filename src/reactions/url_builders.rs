@@ -0,0 +1,118 @@
+use types::{
+  Url,
+  IssueId,
+};
+use types::comments::CommentId;
+use types::reactions::ReactionId;
+use types::repos::Repository;
+
+use repos::url_builders::repo_at;
+use issue_comments::url_builders::issue_comment_at;
+use commit_comments::url_builders::pull_request_comment_at;
+
+pub fn issue_reactions(repo: &Repository, issue_id: &IssueId) -> Url {
+  repo_at(repo) + "/issues/" + &issue_id.to_string() + "/reactions"
+}
+
+pub fn issue_reaction_at(repo: &Repository, issue_id: &IssueId, reaction_id: &ReactionId) -> Url {
+  issue_reactions(repo, issue_id) + "/" + &reaction_id.to_string()
+}
+
+pub fn issue_comment_reactions(repo: &Repository, comment_id: &CommentId) -> Url {
+  issue_comment_at(repo, comment_id) + "/reactions"
+}
+
+pub fn issue_comment_reaction_at(repo: &Repository, comment_id: &CommentId, reaction_id: &ReactionId) -> Url {
+  issue_comment_reactions(repo, comment_id) + "/" + &reaction_id.to_string()
+}
+
+pub fn pull_request_comment_reactions(repo: &Repository, comment_id: &CommentId) -> Url {
+  pull_request_comment_at(repo, comment_id) + "/reactions"
+}
+
+pub fn pull_request_comment_reaction_at(repo: &Repository, comment_id: &CommentId, reaction_id: &ReactionId) -> Url {
+  pull_request_comment_reactions(repo, comment_id) + "/" + &reaction_id.to_string()
+}
+
+pub fn commit_comment_reactions(repo: &Repository, comment_id: &CommentId) -> Url {
+  repo_at(repo) + "/comments/" + &comment_id.to_string() + "/reactions"
+}
+
+pub fn commit_comment_reaction_at(repo: &Repository, comment_id: &CommentId, reaction_id: &ReactionId) -> Url {
+  commit_comment_reactions(repo, comment_id) + "/" + &reaction_id.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+
+  use types::repos::Repository;
+
+  use super::{
+    issue_reactions,
+    issue_reaction_at,
+    issue_comment_reactions,
+    issue_comment_reaction_at,
+    pull_request_comment_reactions,
+    pull_request_comment_reaction_at,
+    commit_comment_reactions,
+    commit_comment_reaction_at,
+  };
+
+  #[test]
+  fn it_builds_issue_reactions() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/issues/21/reactions";
+    expect!(issue_reactions(&repo, &21)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_issue_reaction_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/issues/21/reactions/3";
+    expect!(issue_reaction_at(&repo, &21, &3)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_issue_comment_reactions() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/issues/comments/21/reactions";
+    expect!(issue_comment_reactions(&repo, &21)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_issue_comment_reaction_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/issues/comments/21/reactions/3";
+    expect!(issue_comment_reaction_at(&repo, &21, &3)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_pull_request_comment_reactions() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/pulls/comments/21/reactions";
+    expect!(pull_request_comment_reactions(&repo, &21)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_pull_request_comment_reaction_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/pulls/comments/21/reactions/3";
+    expect!(pull_request_comment_reaction_at(&repo, &21, &3)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_commit_comment_reactions() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/comments/21/reactions";
+    expect!(commit_comment_reactions(&repo, &21)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_commit_comment_reaction_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/comments/21/reactions/3";
+    expect!(commit_comment_reaction_at(&repo, &21, &3)).to(be_equal_to(expected));
+  }
+}
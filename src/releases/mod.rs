@@ -0,0 +1,133 @@
+pub mod url_builders;
+
+use std::io::{Read, Write};
+
+use hyper::method::Method;
+use hyper::mime::{Mime, TopLevel, SubLevel};
+
+use github_client::{
+  SimpleClient,
+  deleted_status,
+  url_with_query,
+};
+
+use types::{
+  GitErr,
+  PageQuery,
+  DeletedStatus,
+};
+
+use types::git_data::TagName;
+
+use types::releases::{
+  ReleaseId,
+  AssetId,
+  Release,
+  ReleaseAsset,
+  CreateRelease,
+  EditRelease,
+  GenerateReleaseNotes,
+  ReleaseNotes,
+  UploadAsset,
+  EditAsset,
+};
+
+use types::repos::Repository;
+
+pub trait Releaser {
+  fn list_releases(&self, repo: Repository, page: Option<PageQuery>) -> Result<Vec<Release>, GitErr>;
+  fn get_release(&self, repo: Repository, release_id: ReleaseId) -> Result<Release, GitErr>;
+  fn get_latest_release(&self, repo: Repository) -> Result<Release, GitErr>;
+  fn get_release_by_tag(&self, repo: Repository, tag: TagName) -> Result<Release, GitErr>;
+  fn create_release(&self, repo: Repository, details: CreateRelease) -> Result<Release, GitErr>;
+  fn edit_release(&self, repo: Repository, release_id: ReleaseId, details: EditRelease) -> Result<Release, GitErr>;
+  fn delete_release(&self, repo: Repository, release_id: ReleaseId) -> Result<DeletedStatus, GitErr>;
+  fn generate_release_notes(&self, repo: Repository, details: GenerateReleaseNotes) -> Result<ReleaseNotes, GitErr>;
+  fn list_assets(&self, repo: Repository, release_id: ReleaseId) -> Result<Vec<ReleaseAsset>, GitErr>;
+  fn get_asset(&self, repo: Repository, asset_id: AssetId) -> Result<ReleaseAsset, GitErr>;
+  fn edit_asset(&self, repo: Repository, asset_id: AssetId, details: EditAsset) -> Result<ReleaseAsset, GitErr>;
+  fn delete_asset(&self, repo: Repository, asset_id: AssetId) -> Result<DeletedStatus, GitErr>;
+  fn upload_asset<R: Read>(&self, repo: Repository, release_id: ReleaseId, details: UploadAsset, content_type: Mime, content: &mut R, length: u64) -> Result<ReleaseAsset, GitErr>;
+  fn download_asset<W: Write>(&self, repo: Repository, asset_id: AssetId, out: &mut W) -> Result<u64, GitErr>;
+}
+
+impl<C: SimpleClient> Releaser for C {
+  fn list_releases(&self, repo: Repository, page: Option<PageQuery>) -> Result<Vec<Release>, GitErr> {
+    let url = url_builders::releases(&repo);
+    match page {
+      Some(page) => self.request_with_query(Method::Get, url, page),
+      None => self.request_without_payload(Method::Get, url)
+    }
+  }
+
+  fn get_release(&self, repo: Repository, release_id: ReleaseId) -> Result<Release, GitErr> {
+    let url = url_builders::release_at(&repo, &release_id);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn get_latest_release(&self, repo: Repository) -> Result<Release, GitErr> {
+    let url = url_builders::latest_release(&repo);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn get_release_by_tag(&self, repo: Repository, tag: TagName) -> Result<Release, GitErr> {
+    let url = url_builders::release_by_tag(&repo, &tag);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn create_release(&self, repo: Repository, details: CreateRelease) -> Result<Release, GitErr> {
+    let url = url_builders::releases(&repo);
+    self.request_with_payload(Method::Post, url, details)
+  }
+
+  fn edit_release(&self, repo: Repository, release_id: ReleaseId, details: EditRelease) -> Result<Release, GitErr> {
+    let url = url_builders::release_at(&repo, &release_id);
+    self.request_with_payload(Method::Patch, url, details)
+  }
+
+  fn delete_release(&self, repo: Repository, release_id: ReleaseId) -> Result<DeletedStatus, GitErr> {
+    let url = url_builders::release_at(&repo, &release_id);
+    self
+      .request_for_status(Method::Delete, url)
+      .and_then(deleted_status)
+  }
+
+  fn generate_release_notes(&self, repo: Repository, details: GenerateReleaseNotes) -> Result<ReleaseNotes, GitErr> {
+    let url = url_builders::generate_release_notes(&repo);
+    self.request_with_payload(Method::Post, url, details)
+  }
+
+  fn list_assets(&self, repo: Repository, release_id: ReleaseId) -> Result<Vec<ReleaseAsset>, GitErr> {
+    let url = url_builders::release_assets(&repo, &release_id);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn get_asset(&self, repo: Repository, asset_id: AssetId) -> Result<ReleaseAsset, GitErr> {
+    let url = url_builders::release_asset_at(&repo, &asset_id);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn edit_asset(&self, repo: Repository, asset_id: AssetId, details: EditAsset) -> Result<ReleaseAsset, GitErr> {
+    let url = url_builders::release_asset_at(&repo, &asset_id);
+    self.request_with_payload(Method::Patch, url, details)
+  }
+
+  fn delete_asset(&self, repo: Repository, asset_id: AssetId) -> Result<DeletedStatus, GitErr> {
+    let url = url_builders::release_asset_at(&repo, &asset_id);
+    self
+      .request_for_status(Method::Delete, url)
+      .and_then(deleted_status)
+  }
+
+  fn upload_asset<R: Read>(&self, repo: Repository, release_id: ReleaseId, details: UploadAsset, content_type: Mime, content: &mut R, length: u64) -> Result<ReleaseAsset, GitErr> {
+    let url = url_builders::release_asset_upload(&repo, &release_id);
+    url_with_query(url, &details)
+      .and_then(|url| self.request_with_raw_payload(Method::Post, url, content_type, content, length))
+  }
+
+  fn download_asset<W: Write>(&self, repo: Repository, asset_id: AssetId, out: &mut W) -> Result<u64, GitErr> {
+    let url = url_builders::release_asset_at(&repo, &asset_id);
+    let octet_stream = Mime(TopLevel::Application, SubLevel::Ext("octet-stream".to_owned()), vec![]);
+    self.request_to_writer(Method::Get, url, octet_stream, out)
+  }
+}
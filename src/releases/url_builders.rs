@@ -0,0 +1,120 @@
+use types::Url;
+use types::git_data::TagName;
+use types::releases::{
+  ReleaseId,
+  AssetId,
+};
+use types::repos::Repository;
+
+use repos::url_builders::repo_at;
+
+pub fn releases(repo: &Repository) -> Url {
+  repo_at(repo) + "/releases"
+}
+
+pub fn release_at(repo: &Repository, release_id: &ReleaseId) -> Url {
+  releases(repo) + "/" + &release_id.to_string()
+}
+
+pub fn latest_release(repo: &Repository) -> Url {
+  releases(repo) + "/latest"
+}
+
+pub fn release_by_tag(repo: &Repository, tag: &TagName) -> Url {
+  releases(repo) + "/tags/" + tag
+}
+
+pub fn generate_release_notes(repo: &Repository) -> Url {
+  releases(repo) + "/generate-notes"
+}
+
+pub fn release_assets(repo: &Repository, release_id: &ReleaseId) -> Url {
+  release_at(repo, release_id) + "/assets"
+}
+
+pub fn release_asset_at(repo: &Repository, asset_id: &AssetId) -> Url {
+  releases(repo) + "/assets/" + &asset_id.to_string()
+}
+
+// Asset uploads go through a separate host
+pub fn release_asset_upload(repo: &Repository, release_id: &ReleaseId) -> Url {
+  "https://uploads.github.com/repos/".to_owned() +
+    &repo.owner + "/" +
+    &repo.repo_name + "/releases/" +
+    &release_id.to_string() + "/assets"
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+
+  use types::repos::Repository;
+
+  use super::{
+    releases,
+    release_at,
+    latest_release,
+    release_by_tag,
+    generate_release_notes,
+    release_assets,
+    release_asset_at,
+    release_asset_upload,
+  };
+
+  #[test]
+  fn it_builds_releases() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/releases";
+    expect!(releases(&repo)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_release_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/releases/12";
+    expect!(release_at(&repo, &12)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_latest_release() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/releases/latest";
+    expect!(latest_release(&repo)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_release_by_tag() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/releases/tags/v1.0.0";
+    expect!(release_by_tag(&repo, &("v1.0.0".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_generate_release_notes() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/releases/generate-notes";
+    expect!(generate_release_notes(&repo)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_release_assets() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/releases/12/assets";
+    expect!(release_assets(&repo, &12)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_release_asset_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/releases/assets/34";
+    expect!(release_asset_at(&repo, &34)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_release_asset_upload() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://uploads.github.com/repos/test_owner/test_repo/releases/12/assets";
+    expect!(release_asset_upload(&repo, &12)).to(be_equal_to(expected));
+  }
+}
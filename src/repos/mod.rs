@@ -1,13 +1,22 @@
 pub mod url_builders;
 
+use std::io::Write;
+
 use hyper::method::Method;
+use hyper::mime::{Mime, TopLevel, SubLevel};
 
-use github_client::{SimpleClient};
+use github_client::{
+  SimpleClient,
+  deleted_status,
+};
+use pagination::collect_pages;
 
 use types::{
   BranchName,
   OrganizationName,
   GitErr,
+  GitRef,
+  PageQuery,
   ContributorsQuery,
   LanguagePile,
   Team,
@@ -24,6 +33,9 @@ use types::repos::{
   PublicReposQuery,
   CreateRepository,
   EditRepository,
+  TransferRepository,
+  Topics,
+  ArchiveFormat,
 };
 use types::users::{
   UserName,
@@ -34,6 +46,8 @@ pub trait Repoer {
   fn list_own_repos(&self, query: RepoQuery) -> Result<Vec<Repo>, GitErr>;
   fn list_user_repos(&self, owner: UserName /*TODO: query: Option<UserRepoQuery>*/) -> Result<Vec<Repo>, GitErr>;
   fn list_org_repos(&self, org: OrganizationName /*TODO: query: Option<OrgRepoQuery>*/) -> Result<Vec<Repo>, GitErr>;
+  fn list_all_org_repos(&self, org: OrganizationName) -> Result<Vec<Repo>, GitErr>;
+  fn list_all_user_repos(&self, owner: UserName) -> Result<Vec<Repo>, GitErr>;
   fn list_public_repos(&self, query: Option<PublicReposQuery>) -> Result<Vec<Repo>, GitErr>;
   fn create_own_repo(&self, details: CreateRepository) -> Result<Repo, GitErr>;
   fn create_org_repo(&self, org: OrganizationName, details: CreateRepository) -> Result<Repo, GitErr>;
@@ -41,11 +55,40 @@ pub trait Repoer {
   fn edit_repo(&self, repo: Repository, details: EditRepository) -> Result<Repo, GitErr>;
   fn list_contributors(&self, repo: Repository, query: ContributorsQuery) -> Result<Vec<GithubUser>, GitErr>;
   fn list_languages(&self, repo: Repository) -> Result<LanguagePile, GitErr>;
+  fn sum_user_languages(&self, owner: UserName) -> Result<LanguagePile, GitErr>;
+  fn sum_org_languages(&self, org: OrganizationName) -> Result<LanguagePile, GitErr>;
   fn list_teams(&self, repo: Repository) -> Result<Vec<Team>, GitErr>;
   fn list_tags(&self, repo: Repository) -> Result<Vec<Tag>, GitErr>;
+  fn list_tags_page(&self, repo: Repository, page: PageQuery) -> Result<Vec<Tag>, GitErr>;
+  fn list_all_tags(&self, repo: Repository) -> Result<Vec<Tag>, GitErr>;
+  fn get_topics(&self, repo: Repository) -> Result<Topics, GitErr>;
+  fn replace_topics(&self, repo: Repository, topics: Topics) -> Result<Topics, GitErr>;
+  fn download_archive<W: Write>(&self, repo: Repository, format: ArchiveFormat, git_ref: GitRef, out: &mut W) -> Result<u64, GitErr>;
+  fn download_tag_archive<W: Write>(&self, tag: Tag, format: ArchiveFormat, out: &mut W) -> Result<u64, GitErr>;
   fn list_branches(&self, repo: Repository) -> Result<Vec<Branch>, GitErr>;
   fn get_branch(&self, repo: Repository, branch: BranchName) -> Result<FullBranch, GitErr>;
   fn delete_repo(&self, repo: Repository) -> Result<DeletedStatus, GitErr>;
+  fn transfer_repo(&self, repo: Repository, details: TransferRepository) -> Result<Repo, GitErr>;
+}
+
+fn octet_stream() -> Mime {
+  Mime(TopLevel::Application, SubLevel::Ext("octet-stream".to_owned()), vec![])
+}
+
+fn sum_languages<C: Repoer>(client: &C, repos: Vec<Repo>) -> Result<LanguagePile, GitErr> {
+  repos
+    .into_iter()
+    .fold(Ok(LanguagePile::new()), |result, repo| {
+      result.and_then(|mut total| {
+        let repository = Repository { owner: repo.owner.login, repo_name: repo.name };
+        client
+          .list_languages(repository)
+          .map(|languages| {
+            total.add(&languages);
+            total
+          })
+      })
+    })
 }
 
 impl<C: SimpleClient> Repoer for C {
@@ -64,6 +107,20 @@ impl<C: SimpleClient> Repoer for C {
     self.request_without_payload(Method::Get, url)
   }
 
+  fn list_all_org_repos(&self, org: OrganizationName) -> Result<Vec<Repo>, GitErr> {
+    collect_pages(|page| {
+      let url = url_builders::organization_repos(&org);
+      self.request_with_query(Method::Get, url, page)
+    })
+  }
+
+  fn list_all_user_repos(&self, owner: UserName) -> Result<Vec<Repo>, GitErr> {
+    collect_pages(|page| {
+      let url = url_builders::user_repos(&owner);
+      self.request_with_query(Method::Get, url, page)
+    })
+  }
+
   fn list_public_repos(&self, query: Option<PublicReposQuery>) -> Result<Vec<Repo>, GitErr> {
     let url = url_builders::all_repos();
     match query {
@@ -102,6 +159,18 @@ impl<C: SimpleClient> Repoer for C {
     self.request_without_payload(Method::Get, url)
   }
 
+  fn sum_user_languages(&self, owner: UserName) -> Result<LanguagePile, GitErr> {
+    self
+      .list_all_user_repos(owner)
+      .and_then(|repos| sum_languages(self, repos))
+  }
+
+  fn sum_org_languages(&self, org: OrganizationName) -> Result<LanguagePile, GitErr> {
+    self
+      .list_all_org_repos(org)
+      .and_then(|repos| sum_languages(self, repos))
+  }
+
   fn list_teams(&self, repo: Repository) -> Result<Vec<Team>, GitErr> {
     let url = url_builders::teams_at(&repo);
     self.request_without_payload(Method::Get, url)
@@ -112,6 +181,35 @@ impl<C: SimpleClient> Repoer for C {
     self.request_without_payload(Method::Get, url)
   }
 
+  fn list_tags_page(&self, repo: Repository, page: PageQuery) -> Result<Vec<Tag>, GitErr> {
+    let url = url_builders::tags_at(&repo);
+    self.request_with_query(Method::Get, url, page)
+  }
+
+  fn list_all_tags(&self, repo: Repository) -> Result<Vec<Tag>, GitErr> {
+    collect_pages(|page| self.list_tags_page(repo.clone(), page))
+  }
+
+  fn get_topics(&self, repo: Repository) -> Result<Topics, GitErr> {
+    let url = url_builders::topics_at(&repo);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn replace_topics(&self, repo: Repository, topics: Topics) -> Result<Topics, GitErr> {
+    let url = url_builders::topics_at(&repo);
+    self.request_with_payload(Method::Put, url, topics)
+  }
+
+  fn download_archive<W: Write>(&self, repo: Repository, format: ArchiveFormat, git_ref: GitRef, out: &mut W) -> Result<u64, GitErr> {
+    let url = url_builders::archive_at(&repo, &format, &git_ref);
+    self.request_to_writer(Method::Get, url, octet_stream(), out)
+  }
+
+  fn download_tag_archive<W: Write>(&self, tag: Tag, format: ArchiveFormat, out: &mut W) -> Result<u64, GitErr> {
+    let url = tag.archive_url(&format).clone();
+    self.request_to_writer(Method::Get, url, octet_stream(), out)
+  }
+
   fn list_branches(&self, repo: Repository) -> Result<Vec<Branch>, GitErr> {
     let url = url_builders::branches_at(&repo);
     self.request_without_payload(Method::Get, url)
@@ -122,8 +220,95 @@ impl<C: SimpleClient> Repoer for C {
     self.request_without_payload(Method::Get, url)
   }
 
-  #[allow(unused_variables)]
   fn delete_repo(&self, repo: Repository) -> Result<DeletedStatus, GitErr> {
-    Err(GitErr::NotImplemented("Repoer#delete_repo".to_owned()))
+    let url = url_builders::repo_at(&repo);
+    self
+      .request_for_status(Method::Delete, url)
+      .and_then(deleted_status)
+  }
+
+  fn transfer_repo(&self, repo: Repository, details: TransferRepository) -> Result<Repo, GitErr> {
+    let url = url_builders::transfer_at(&repo);
+    self.request_with_payload(Method::Post, url, details)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+  use hyper::method::Method;
+  use hyper::status::StatusCode;
+
+  use test_client::{MockClient, MockResponse, repo_json};
+  use types::{GitErr, DeletedStatus};
+  use types::repos::{
+    Repository,
+    EditRepository,
+    TransferRepository,
+  };
+
+  use super::Repoer;
+
+  fn repo() -> Repository {
+    Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() }
+  }
+
+  fn delete_with_status(status: StatusCode) -> Result<DeletedStatus, GitErr> {
+    let client = MockClient::new(vec![MockResponse::status(status)]);
+    client.delete_repo(repo())
+  }
+
+  #[test]
+  fn it_maps_delete_statuses() {
+    expect!(delete_with_status(StatusCode::NoContent)).to(be_equal_to(Ok(DeletedStatus::Deleted)));
+    expect!(delete_with_status(StatusCode::Forbidden)).to(be_equal_to(Ok(DeletedStatus::NotDeleted)));
+    expect!(delete_with_status(StatusCode::NotFound)).to(be_equal_to(Ok(DeletedStatus::NotDeleted)));
+    expect!(delete_with_status(StatusCode::Conflict)).to(be_equal_to(Err(GitErr::UnexpectedStatus(StatusCode::Conflict.to_string()))));
+  }
+
+  #[test]
+  fn it_archives_without_touching_other_settings() {
+    let client = MockClient::new(vec![MockResponse::json(&repo_json("test_owner", "test_repo"))]);
+    let details = EditRepository {
+      name: "test_repo".to_owned(),
+      description: None,
+      homepage: None,
+      private: None,
+      has_issues: None,
+      has_wiki: None,
+      has_downloads: None,
+      default_branch: None,
+      archived: Some(true)
+    };
+
+    client.edit_repo(repo(), details).unwrap();
+
+    let requests = client.requests.borrow();
+    expect!(requests[0].method.clone()).to(be_equal_to(Method::Patch));
+    expect!(requests[0].body.clone()).to(be_equal_to(Some(r#"{"name":"test_repo","archived":true}"#.to_owned())));
+  }
+
+  #[test]
+  fn it_returns_the_repository_of_an_accepted_transfer() {
+    let accepted = MockResponse { status: StatusCode::Accepted, ..MockResponse::json(&repo_json("new_owner", "test_repo")) };
+    let client = MockClient::new(vec![accepted]);
+    let details = TransferRepository { new_owner: "new_owner".to_owned(), new_name: None, team_ids: None };
+
+    let transferred = client.transfer_repo(repo(), details).unwrap();
+
+    expect!(transferred.full_name).to(be_equal_to("new_owner/test_repo".to_owned()));
+    let requests = client.requests.borrow();
+    expect!(requests[0].url.clone()).to(be_equal_to("https://api.github.com/repos/test_owner/test_repo/transfer".to_owned()));
+    expect!(requests[0].body.clone()).to(be_equal_to(Some(r#"{"new_owner":"new_owner"}"#.to_owned())));
+  }
+
+  #[test]
+  fn it_fails_a_rejected_transfer() {
+    let rejected = MockResponse { status: StatusCode::UnprocessableEntity, ..MockResponse::json(r#"{"message": "Validation Failed"}"#) };
+    let client = MockClient::new(vec![rejected]);
+    let details = TransferRepository { new_owner: "new_owner".to_owned(), new_name: None, team_ids: None };
+
+    expect!(client.transfer_repo(repo(), details).is_err()).to(be_equal_to(true));
   }
 }
@@ -1,10 +1,14 @@
 use types::{
   OrganizationName,
   BranchName,
+  GitRef,
   Url
 };
 use types::users::UserName;
-use types::repos::Repository;
+use types::repos::{
+  Repository,
+  ArchiveFormat,
+};
 
 #[allow(dead_code)]
 pub fn own_repos() -> Url {
@@ -36,6 +40,10 @@ pub fn repo_at(repo: &Repository) -> Url {
     &repo.repo_name
 }
 
+pub fn transfer_at(repo: &Repository) -> Url {
+  repo_at(repo) + "/transfer"
+}
+
 #[allow(dead_code)]
 pub fn contributors_at(repo: &Repository) -> Url {
   repo_at(repo) + "/contributors"
@@ -56,6 +64,18 @@ pub fn tags_at(repo: &Repository) -> Url {
   repo_at(repo) + "/tags"
 }
 
+pub fn topics_at(repo: &Repository) -> Url {
+  repo_at(repo) + "/topics"
+}
+
+pub fn archive_at(repo: &Repository, format: &ArchiveFormat, git_ref: &GitRef) -> Url {
+  let format = match *format {
+    ArchiveFormat::Tarball => "/tarball/",
+    ArchiveFormat::Zipball => "/zipball/"
+  };
+  repo_at(repo) + format + git_ref
+}
+
 pub fn branches_at(repo: &Repository) -> Url {
   repo_at(repo) + "/branches"
 }
@@ -70,7 +90,10 @@ mod tests {
   use expectest::core::expect;
   use expectest::matchers::be_equal_to;
 
-  use types::repos::Repository;
+  use types::repos::{
+    Repository,
+    ArchiveFormat,
+  };
 
   use super::{
     own_repos,
@@ -78,10 +101,13 @@ mod tests {
     organization_repos,
     all_repos,
     repo_at,
+    transfer_at,
     contributors_at,
     languages_at,
     teams_at,
     tags_at,
+    topics_at,
+    archive_at,
     branches_at,
     branch_at,
   };
@@ -117,6 +143,13 @@ mod tests {
     expect!(repo_at(&repo)).to(be_equal_to(expected));
   }
 
+  #[test]
+  fn it_builds_transfer_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/transfer";
+    expect!(transfer_at(&repo)).to(be_equal_to(expected));
+  }
+
   #[test]
   fn it_builds_contributors_at() {
     let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
@@ -145,6 +178,20 @@ mod tests {
     expect!(tags_at(&repo)).to(be_equal_to(expected));
   }
 
+  #[test]
+  fn it_builds_topics_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/topics";
+    expect!(topics_at(&repo)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_archive_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/tarball/v1.0";
+    expect!(archive_at(&repo, &ArchiveFormat::Tarball, &("v1.0".to_owned()))).to(be_equal_to(expected));
+  }
+
   #[test]
   fn it_builds_branches_at() {
     let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
@@ -0,0 +1,192 @@
+pub mod url_builders;
+
+use std::cmp;
+use std::thread;
+use std::time::Duration;
+
+use hyper::method::Method;
+use rustc_serialize::{
+  Decodable,
+  Encodable,
+};
+use time;
+
+use github_client::{SimpleClient, url_with_query};
+
+use types::{
+  GitErr,
+  RateLimitedResponse,
+};
+
+use types::repos::Repo;
+use types::users::GithubUser;
+
+use types::search::{
+  SearchQuery,
+  SearchResults,
+  RepoSearchSort,
+  CodeSearchSort,
+  CodeSearchItem,
+  IssueSearchSort,
+  IssueSearchItem,
+  UserSearchSort,
+  CommitSearchSort,
+  CommitSearchItem,
+  LabelSearchQuery,
+  LabelSearchItem,
+  RateLimit,
+};
+
+const MAX_SEARCH_ATTEMPTS: u32 = 3;
+// Waited when a refusal does not say when the limit resets
+const DEFAULT_RATE_LIMIT_WAIT_SECS: u64 = 60;
+
+/// Searches have a much lower rate limit than the rest of the API. A search
+/// refused for exceeding it is retried once the limit resets, up to
+/// MAX_SEARCH_ATTEMPTS times.
+pub trait Searcher {
+  fn search_repositories(&self, query: SearchQuery<RepoSearchSort>) -> Result<SearchResults<Repo>, GitErr>;
+  fn search_code(&self, query: SearchQuery<CodeSearchSort>) -> Result<SearchResults<CodeSearchItem>, GitErr>;
+  fn search_issues(&self, query: SearchQuery<IssueSearchSort>) -> Result<SearchResults<IssueSearchItem>, GitErr>;
+  fn search_users(&self, query: SearchQuery<UserSearchSort>) -> Result<SearchResults<GithubUser>, GitErr>;
+  fn search_commits(&self, query: SearchQuery<CommitSearchSort>) -> Result<SearchResults<CommitSearchItem>, GitErr>;
+  fn search_labels(&self, query: LabelSearchQuery) -> Result<SearchResults<LabelSearchItem>, GitErr>;
+  fn get_rate_limit(&self) -> Result<RateLimit, GitErr>;
+}
+
+fn rate_limit_wait<D>(response: &RateLimitedResponse<D>, now: i64) -> Duration {
+  match (response.retry_after, response.reset) {
+    (Some(retry_after), _) => retry_after,
+    // One more second, as the reset is rounded down to the second
+    (None, Some(reset)) => Duration::from_secs(cmp::max(reset - now, 0) as u64 + 1),
+    (None, None) => Duration::from_secs(DEFAULT_RATE_LIMIT_WAIT_SECS)
+  }
+}
+
+fn search<C, D, E>(client: &C, kind: &str, query: E) -> Result<D, GitErr>
+  where C: SimpleClient, D: Decodable, E: Encodable {
+  let url = try!(url_with_query(url_builders::search_at(kind), &query));
+
+  for attempt in 1..(MAX_SEARCH_ATTEMPTS + 1) {
+    let response = try!(client.request_rate_limited(Method::Get, url.clone()));
+    let wait = rate_limit_wait(&response, time::get_time().sec);
+    match response.content {
+      Some(results) => return Ok(results),
+      None => if attempt < MAX_SEARCH_ATTEMPTS { thread::sleep(wait) }
+    }
+  }
+
+  Err(GitErr::RetriesExhausted(format!("search rate limit still exceeded after {} attempts", MAX_SEARCH_ATTEMPTS)))
+}
+
+impl<C: SimpleClient> Searcher for C {
+  fn search_repositories(&self, query: SearchQuery<RepoSearchSort>) -> Result<SearchResults<Repo>, GitErr> {
+    search(self, "repositories", query)
+  }
+
+  fn search_code(&self, query: SearchQuery<CodeSearchSort>) -> Result<SearchResults<CodeSearchItem>, GitErr> {
+    search(self, "code", query)
+  }
+
+  fn search_issues(&self, query: SearchQuery<IssueSearchSort>) -> Result<SearchResults<IssueSearchItem>, GitErr> {
+    search(self, "issues", query)
+  }
+
+  fn search_users(&self, query: SearchQuery<UserSearchSort>) -> Result<SearchResults<GithubUser>, GitErr> {
+    search(self, "users", query)
+  }
+
+  fn search_commits(&self, query: SearchQuery<CommitSearchSort>) -> Result<SearchResults<CommitSearchItem>, GitErr> {
+    search(self, "commits", query)
+  }
+
+  fn search_labels(&self, query: LabelSearchQuery) -> Result<SearchResults<LabelSearchItem>, GitErr> {
+    search(self, "labels", query)
+  }
+
+  fn get_rate_limit(&self) -> Result<RateLimit, GitErr> {
+    let url = url_builders::rate_limit();
+    self.request_without_payload(Method::Get, url)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::time::Duration;
+
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+  use hyper::status::StatusCode;
+
+  use test_client::{MockClient, MockResponse};
+  use types::{
+    GitErr,
+    RateLimitedResponse,
+  };
+  use types::search::{
+    SearchQuery,
+    UserSearchSort,
+  };
+
+  use super::{
+    rate_limit_wait,
+    Searcher,
+    MAX_SEARCH_ATTEMPTS,
+  };
+
+  fn limited(reset: Option<i64>, retry_after: Option<u64>) -> RateLimitedResponse<()> {
+    RateLimitedResponse { content: None, remaining: Some(0), reset: reset, retry_after: retry_after.map(Duration::from_secs) }
+  }
+
+  fn query() -> SearchQuery<UserSearchSort> {
+    SearchQuery { q: "tom".to_owned(), sort: None, order: None, per_page: None, page: None }
+  }
+
+  fn no_results() -> MockResponse {
+    MockResponse::json(r#"{"total_count": 0, "incomplete_results": false, "items": []}"#)
+  }
+
+  fn refused() -> MockResponse {
+    MockResponse::status(StatusCode::Forbidden).header("X-RateLimit-Remaining", "0").header("Retry-After", "0")
+  }
+
+  #[test]
+  fn it_waits_for_retry_after_first() {
+    expect!(rate_limit_wait(&limited(Some(1000), Some(5)), 990)).to(be_equal_to(Duration::from_secs(5)));
+  }
+
+  #[test]
+  fn it_waits_until_just_past_the_reset() {
+    expect!(rate_limit_wait(&limited(Some(1000), None), 990)).to(be_equal_to(Duration::from_secs(11)));
+    expect!(rate_limit_wait(&limited(Some(1000), None), 1005)).to(be_equal_to(Duration::from_secs(1)));
+  }
+
+  #[test]
+  fn it_waits_a_minute_without_any_hint() {
+    expect!(rate_limit_wait(&limited(None, None), 990)).to(be_equal_to(Duration::from_secs(60)));
+  }
+
+  #[test]
+  fn it_searches_without_checking_the_rate_limit_first() {
+    let client = MockClient::new(vec![no_results()]);
+    let results = client.search_users(query()).unwrap();
+    expect!(results.total_count).to(be_equal_to(0));
+    expect!(client.requests.borrow()[0].url.clone()).to(be_equal_to("https://api.github.com/search/users?q=tom".to_owned()));
+  }
+
+  #[test]
+  fn it_retries_a_search_refused_for_the_rate_limit() {
+    let client = MockClient::new(vec![refused(), no_results()]);
+    expect!(client.search_users(query()).is_ok()).to(be_equal_to(true));
+    expect!(client.request_count()).to(be_equal_to(2));
+  }
+
+  #[test]
+  fn it_gives_up_when_the_rate_limit_persists() {
+    let client = MockClient::new((0..MAX_SEARCH_ATTEMPTS).map(|_| refused()).collect());
+    match client.search_users(query()) {
+      Err(GitErr::RetriesExhausted(_)) => (),
+      other => panic!("expected RetriesExhausted, got {:?}", other)
+    }
+  }
+}
@@ -0,0 +1,32 @@
+use types::Url;
+
+pub fn search_at(kind: &str) -> Url {
+  "https://api.github.com/search/".to_owned() + kind
+}
+
+pub fn rate_limit() -> Url {
+  "https://api.github.com/rate_limit".to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+
+  use super::{
+    search_at,
+    rate_limit,
+  };
+
+  #[test]
+  fn it_builds_search_at() {
+    let expected = "https://api.github.com/search/repositories";
+    expect!(search_at("repositories")).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_rate_limit() {
+    let expected = "https://api.github.com/rate_limit";
+    expect!(rate_limit()).to(be_equal_to(expected));
+  }
+}
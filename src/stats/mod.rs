@@ -0,0 +1,108 @@
+pub mod url_builders;
+
+use hyper::method::Method;
+use rustc_serialize::Decodable;
+
+use github_client::{SimpleClient};
+use polling::poll_until_ready;
+
+use types::{
+  Url,
+  GitErr,
+  PollOptions,
+};
+
+use types::repos::Repository;
+
+use types::stats::{
+  ContributorStats,
+  CommitActivity,
+  CodeFrequency,
+  Participation,
+  PunchCardHour,
+};
+
+/// Github computes statistics in the background and answers 202 Accepted
+/// until they are cached, so every request is repeated until the data is
+/// ready or `options.timeout` has elapsed. An empty repository has empty
+/// statistics.
+pub trait Statistician {
+  fn get_contributor_stats(&self, repo: Repository, options: PollOptions) -> Result<Vec<ContributorStats>, GitErr>;
+  fn get_commit_activity(&self, repo: Repository, options: PollOptions) -> Result<Vec<CommitActivity>, GitErr>;
+  fn get_code_frequency(&self, repo: Repository, options: PollOptions) -> Result<Vec<CodeFrequency>, GitErr>;
+  fn get_participation(&self, repo: Repository, options: PollOptions) -> Result<Participation, GitErr>;
+  fn get_punch_card(&self, repo: Repository, options: PollOptions) -> Result<Vec<PunchCardHour>, GitErr>;
+}
+
+fn poll_stats<C: SimpleClient, D: Decodable + Default>(client: &C, url: Url, options: &PollOptions) -> Result<D, GitErr> {
+  let description = "statistics at ".to_owned() + &url;
+  poll_until_ready(options, &description, || client.request_if_ready(Method::Get, url.clone()))
+}
+
+impl<C: SimpleClient> Statistician for C {
+  fn get_contributor_stats(&self, repo: Repository, options: PollOptions) -> Result<Vec<ContributorStats>, GitErr> {
+    let url = url_builders::contributor_stats(&repo);
+    poll_stats(self, url, &options)
+  }
+
+  fn get_commit_activity(&self, repo: Repository, options: PollOptions) -> Result<Vec<CommitActivity>, GitErr> {
+    let url = url_builders::commit_activity(&repo);
+    poll_stats(self, url, &options)
+  }
+
+  fn get_code_frequency(&self, repo: Repository, options: PollOptions) -> Result<Vec<CodeFrequency>, GitErr> {
+    let url = url_builders::code_frequency(&repo);
+    poll_stats(self, url, &options)
+  }
+
+  fn get_participation(&self, repo: Repository, options: PollOptions) -> Result<Participation, GitErr> {
+    let url = url_builders::participation(&repo);
+    poll_stats(self, url, &options)
+  }
+
+  fn get_punch_card(&self, repo: Repository, options: PollOptions) -> Result<Vec<PunchCardHour>, GitErr> {
+    let url = url_builders::punch_card(&repo);
+    poll_stats(self, url, &options)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::time::Duration;
+
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+  use hyper::status::StatusCode;
+
+  use test_client::{MockClient, MockResponse};
+  use types::PollOptions;
+  use types::repos::Repository;
+  use types::stats::PunchCardHour;
+
+  use super::Statistician;
+
+  fn repo() -> Repository {
+    Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() }
+  }
+
+  fn options() -> PollOptions {
+    PollOptions { timeout: Duration::from_millis(100), initial_interval: Duration::from_millis(1), max_interval: Duration::from_millis(1) }
+  }
+
+  #[test]
+  fn it_polls_until_statistics_are_ready() {
+    let client = MockClient::new(vec![
+      MockResponse::status(StatusCode::Accepted),
+      MockResponse::json("[[0, 9, 3]]"),
+    ]);
+    let expected = vec![PunchCardHour { day: 0, hour: 9, commits: 3 }];
+    expect!(client.get_punch_card(repo(), options())).to(be_equal_to(Ok(expected)));
+    expect!(client.request_count()).to(be_equal_to(2));
+  }
+
+  #[test]
+  fn it_returns_empty_statistics_for_an_empty_repository() {
+    let client = MockClient::new(vec![MockResponse::status(StatusCode::NoContent)]);
+    expect!(client.get_punch_card(repo(), options())).to(be_equal_to(Ok(vec![])));
+  }
+}
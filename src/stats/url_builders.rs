@@ -0,0 +1,79 @@
+use types::Url;
+use types::repos::Repository;
+
+use repos::url_builders::repo_at;
+
+pub fn stats_at(repo: &Repository) -> Url {
+  repo_at(repo) + "/stats"
+}
+
+pub fn contributor_stats(repo: &Repository) -> Url {
+  stats_at(repo) + "/contributors"
+}
+
+pub fn commit_activity(repo: &Repository) -> Url {
+  stats_at(repo) + "/commit_activity"
+}
+
+pub fn code_frequency(repo: &Repository) -> Url {
+  stats_at(repo) + "/code_frequency"
+}
+
+pub fn participation(repo: &Repository) -> Url {
+  stats_at(repo) + "/participation"
+}
+
+pub fn punch_card(repo: &Repository) -> Url {
+  stats_at(repo) + "/punch_card"
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+
+  use types::repos::Repository;
+
+  use super::{
+    contributor_stats,
+    commit_activity,
+    code_frequency,
+    participation,
+    punch_card,
+  };
+
+  #[test]
+  fn it_builds_contributor_stats() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/stats/contributors";
+    expect!(contributor_stats(&repo)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_commit_activity() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/stats/commit_activity";
+    expect!(commit_activity(&repo)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_code_frequency() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/stats/code_frequency";
+    expect!(code_frequency(&repo)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_participation() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/stats/participation";
+    expect!(participation(&repo)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_punch_card() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/stats/punch_card";
+    expect!(punch_card(&repo)).to(be_equal_to(expected));
+  }
+}
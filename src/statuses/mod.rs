@@ -0,0 +1,42 @@
+pub mod url_builders;
+
+use hyper::method::Method;
+
+use github_client::{SimpleClient};
+
+use types::{
+  GitErr,
+  GitRef,
+  Sha,
+};
+
+use types::statuses::{
+  CreateStatus,
+  CommitStatus,
+  CombinedStatus,
+};
+
+use types::repos::Repository;
+
+pub trait Statuser {
+  fn create_status(&self, repo: Repository, sha: Sha, details: CreateStatus) -> Result<CommitStatus, GitErr>;
+  fn list_statuses(&self, repo: Repository, git_ref: GitRef) -> Result<Vec<CommitStatus>, GitErr>;
+  fn get_combined_status(&self, repo: Repository, git_ref: GitRef) -> Result<CombinedStatus, GitErr>;
+}
+
+impl<C: SimpleClient> Statuser for C {
+  fn create_status(&self, repo: Repository, sha: Sha, details: CreateStatus) -> Result<CommitStatus, GitErr> {
+    let url = url_builders::statuses_at(&repo, &sha);
+    self.request_with_payload(Method::Post, url, details)
+  }
+
+  fn list_statuses(&self, repo: Repository, git_ref: GitRef) -> Result<Vec<CommitStatus>, GitErr> {
+    let url = url_builders::commit_statuses(&repo, &git_ref);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn get_combined_status(&self, repo: Repository, git_ref: GitRef) -> Result<CombinedStatus, GitErr> {
+    let url = url_builders::combined_status(&repo, &git_ref);
+    self.request_without_payload(Method::Get, url)
+  }
+}
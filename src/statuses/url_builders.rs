@@ -0,0 +1,56 @@
+use types::{
+  Url,
+  Sha,
+  GitRef,
+};
+use types::repos::Repository;
+
+use repos::url_builders::repo_at;
+use commits::url_builders::commit_at;
+
+pub fn statuses_at(repo: &Repository, sha: &Sha) -> Url {
+  repo_at(repo) + "/statuses/" + sha
+}
+
+pub fn commit_statuses(repo: &Repository, git_ref: &GitRef) -> Url {
+  commit_at(repo, git_ref) + "/statuses"
+}
+
+pub fn combined_status(repo: &Repository, git_ref: &GitRef) -> Url {
+  commit_at(repo, git_ref) + "/status"
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+
+  use types::repos::Repository;
+
+  use super::{
+    statuses_at,
+    commit_statuses,
+    combined_status,
+  };
+
+  #[test]
+  fn it_builds_statuses_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/statuses/abc123";
+    expect!(statuses_at(&repo, &("abc123".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_commit_statuses() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/commits/master/statuses";
+    expect!(commit_statuses(&repo, &("master".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_combined_status() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/repos/test_owner/test_repo/commits/master/status";
+    expect!(combined_status(&repo, &("master".to_owned()))).to(be_equal_to(expected));
+  }
+}
@@ -0,0 +1,152 @@
+pub mod url_builders;
+
+use hyper::method::Method;
+use hyper::status::StatusCode;
+
+use github_client::{
+  SimpleClient,
+  deleted_status,
+};
+
+use types::{
+  GitErr,
+  OrganizationName,
+  PageQuery,
+  DeletedStatus,
+  Team,
+};
+
+use types::repos::{
+  Repo,
+  Repository,
+};
+
+use types::teams::{
+  TeamSlug,
+  FullTeam,
+  CreateTeam,
+  EditTeam,
+  TeamMembersQuery,
+  TeamMembership,
+  SetTeamMembership,
+  SetTeamRepoPermission,
+  TeamRepoStatus,
+};
+
+use types::users::{
+  UserName,
+  GithubUser,
+};
+
+pub trait Teamer {
+  fn list_org_teams(&self, org: OrganizationName, page: Option<PageQuery>) -> Result<Vec<Team>, GitErr>;
+  fn get_team(&self, org: OrganizationName, team: TeamSlug) -> Result<FullTeam, GitErr>;
+  fn create_team(&self, org: OrganizationName, details: CreateTeam) -> Result<FullTeam, GitErr>;
+  fn edit_team(&self, org: OrganizationName, team: TeamSlug, details: EditTeam) -> Result<FullTeam, GitErr>;
+  fn delete_team(&self, org: OrganizationName, team: TeamSlug) -> Result<DeletedStatus, GitErr>;
+  fn list_team_members(&self, org: OrganizationName, team: TeamSlug, query: Option<TeamMembersQuery>) -> Result<Vec<GithubUser>, GitErr>;
+  fn get_team_membership(&self, org: OrganizationName, team: TeamSlug, user: UserName) -> Result<TeamMembership, GitErr>;
+  fn set_team_membership(&self, org: OrganizationName, team: TeamSlug, user: UserName, details: SetTeamMembership) -> Result<TeamMembership, GitErr>;
+  fn remove_team_membership(&self, org: OrganizationName, team: TeamSlug, user: UserName) -> Result<DeletedStatus, GitErr>;
+  fn list_team_repos(&self, org: OrganizationName, team: TeamSlug) -> Result<Vec<Repo>, GitErr>;
+  fn is_team_repo(&self, org: OrganizationName, team: TeamSlug, repo: Repository) -> Result<TeamRepoStatus, GitErr>;
+  fn set_team_repo_permission(&self, org: OrganizationName, team: TeamSlug, repo: Repository, details: SetTeamRepoPermission) -> Result<TeamRepoStatus, GitErr>;
+  fn remove_team_repo(&self, org: OrganizationName, team: TeamSlug, repo: Repository) -> Result<DeletedStatus, GitErr>;
+  fn list_child_teams(&self, org: OrganizationName, team: TeamSlug) -> Result<Vec<Team>, GitErr>;
+}
+
+impl<C: SimpleClient> Teamer for C {
+  fn list_org_teams(&self, org: OrganizationName, page: Option<PageQuery>) -> Result<Vec<Team>, GitErr> {
+    let url = url_builders::org_teams(&org);
+    match page {
+      Some(page) => self.request_with_query(Method::Get, url, page),
+      None => self.request_without_payload(Method::Get, url)
+    }
+  }
+
+  fn get_team(&self, org: OrganizationName, team: TeamSlug) -> Result<FullTeam, GitErr> {
+    let url = url_builders::team_at(&org, &team);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn create_team(&self, org: OrganizationName, details: CreateTeam) -> Result<FullTeam, GitErr> {
+    let url = url_builders::org_teams(&org);
+    self.request_with_payload(Method::Post, url, details)
+  }
+
+  fn edit_team(&self, org: OrganizationName, team: TeamSlug, details: EditTeam) -> Result<FullTeam, GitErr> {
+    let url = url_builders::team_at(&org, &team);
+    self.request_with_payload(Method::Patch, url, details)
+  }
+
+  fn delete_team(&self, org: OrganizationName, team: TeamSlug) -> Result<DeletedStatus, GitErr> {
+    let url = url_builders::team_at(&org, &team);
+    self
+      .request_for_status(Method::Delete, url)
+      .and_then(deleted_status)
+  }
+
+  fn list_team_members(&self, org: OrganizationName, team: TeamSlug, query: Option<TeamMembersQuery>) -> Result<Vec<GithubUser>, GitErr> {
+    let url = url_builders::team_members(&org, &team);
+    match query {
+      Some(query) => self.request_with_query(Method::Get, url, query),
+      None => self.request_without_payload(Method::Get, url)
+    }
+  }
+
+  fn get_team_membership(&self, org: OrganizationName, team: TeamSlug, user: UserName) -> Result<TeamMembership, GitErr> {
+    let url = url_builders::team_membership_at(&org, &team, &user);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn set_team_membership(&self, org: OrganizationName, team: TeamSlug, user: UserName, details: SetTeamMembership) -> Result<TeamMembership, GitErr> {
+    let url = url_builders::team_membership_at(&org, &team, &user);
+    self.request_with_payload(Method::Put, url, details)
+  }
+
+  fn remove_team_membership(&self, org: OrganizationName, team: TeamSlug, user: UserName) -> Result<DeletedStatus, GitErr> {
+    let url = url_builders::team_membership_at(&org, &team, &user);
+    self
+      .request_for_status(Method::Delete, url)
+      .and_then(deleted_status)
+  }
+
+  fn list_team_repos(&self, org: OrganizationName, team: TeamSlug) -> Result<Vec<Repo>, GitErr> {
+    let url = url_builders::team_repos(&org, &team);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn is_team_repo(&self, org: OrganizationName, team: TeamSlug, repo: Repository) -> Result<TeamRepoStatus, GitErr> {
+    let url = url_builders::team_repo_at(&org, &team, &repo);
+    self
+      .request_for_status(Method::Get, url)
+      .and_then(|status| match status {
+        StatusCode::NoContent => Ok(TeamRepoStatus::Managed),
+        StatusCode::NotFound => Ok(TeamRepoStatus::NotManaged),
+        status => Err(GitErr::UnexpectedStatus(status.to_string()))
+      })
+  }
+
+  fn set_team_repo_permission(&self, org: OrganizationName, team: TeamSlug, repo: Repository, details: SetTeamRepoPermission) -> Result<TeamRepoStatus, GitErr> {
+    let url = url_builders::team_repo_at(&org, &team, &repo);
+    self
+      .request_with_payload_for_status(Method::Put, url, details)
+      .and_then(|status| match status {
+        StatusCode::NoContent => Ok(TeamRepoStatus::Managed),
+        StatusCode::Forbidden | StatusCode::NotFound => Ok(TeamRepoStatus::NotManaged),
+        status => Err(GitErr::UnexpectedStatus(status.to_string()))
+      })
+  }
+
+  fn remove_team_repo(&self, org: OrganizationName, team: TeamSlug, repo: Repository) -> Result<DeletedStatus, GitErr> {
+    let url = url_builders::team_repo_at(&org, &team, &repo);
+    self
+      .request_for_status(Method::Delete, url)
+      .and_then(deleted_status)
+  }
+
+  fn list_child_teams(&self, org: OrganizationName, team: TeamSlug) -> Result<Vec<Team>, GitErr> {
+    let url = url_builders::child_teams(&org, &team);
+    self.request_without_payload(Method::Get, url)
+  }
+}
@@ -0,0 +1,98 @@
+use types::{
+  Url,
+  OrganizationName,
+};
+use types::repos::Repository;
+use types::teams::TeamSlug;
+use types::users::UserName;
+
+use orgs::url_builders::org_at;
+
+pub fn org_teams(org: &OrganizationName) -> Url {
+  org_at(org) + "/teams"
+}
+
+pub fn team_at(org: &OrganizationName, team: &TeamSlug) -> Url {
+  org_teams(org) + "/" + team
+}
+
+pub fn team_members(org: &OrganizationName, team: &TeamSlug) -> Url {
+  team_at(org, team) + "/members"
+}
+
+pub fn team_membership_at(org: &OrganizationName, team: &TeamSlug, user: &UserName) -> Url {
+  team_at(org, team) + "/memberships/" + user
+}
+
+pub fn team_repos(org: &OrganizationName, team: &TeamSlug) -> Url {
+  team_at(org, team) + "/repos"
+}
+
+pub fn team_repo_at(org: &OrganizationName, team: &TeamSlug, repo: &Repository) -> Url {
+  team_repos(org, team) + "/" + &repo.owner + "/" + &repo.repo_name
+}
+
+pub fn child_teams(org: &OrganizationName, team: &TeamSlug) -> Url {
+  team_at(org, team) + "/teams"
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+
+  use types::repos::Repository;
+
+  use super::{
+    org_teams,
+    team_at,
+    team_members,
+    team_membership_at,
+    team_repos,
+    team_repo_at,
+    child_teams,
+  };
+
+  #[test]
+  fn it_builds_org_teams() {
+    let expected = "https://api.github.com/orgs/test_org/teams";
+    expect!(org_teams(&("test_org".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_team_at() {
+    let expected = "https://api.github.com/orgs/test_org/teams/test_team";
+    expect!(team_at(&("test_org".to_owned()), &("test_team".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_team_members() {
+    let expected = "https://api.github.com/orgs/test_org/teams/test_team/members";
+    expect!(team_members(&("test_org".to_owned()), &("test_team".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_team_membership_at() {
+    let expected = "https://api.github.com/orgs/test_org/teams/test_team/memberships/test_user";
+    expect!(team_membership_at(&("test_org".to_owned()), &("test_team".to_owned()), &("test_user".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_team_repos() {
+    let expected = "https://api.github.com/orgs/test_org/teams/test_team/repos";
+    expect!(team_repos(&("test_org".to_owned()), &("test_team".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_team_repo_at() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let expected = "https://api.github.com/orgs/test_org/teams/test_team/repos/test_owner/test_repo";
+    expect!(team_repo_at(&("test_org".to_owned()), &("test_team".to_owned()), &repo)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_child_teams() {
+    let expected = "https://api.github.com/orgs/test_org/teams/test_team/teams";
+    expect!(child_teams(&("test_org".to_owned()), &("test_team".to_owned()))).to(be_equal_to(expected));
+  }
+}
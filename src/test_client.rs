@@ -0,0 +1,208 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::io::{Read, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+use hyper::method::Method;
+use hyper::mime::Mime;
+use hyper::status::StatusCode;
+use rustc_serialize::{
+  json,
+  Encodable,
+  Decodable
+};
+
+use github_client::SimpleClient;
+
+use types::{
+  GitErr,
+  Url,
+  PollResponse,
+  RateLimitedResponse,
+};
+
+/// A canned response. The body is decoded wherever the client is asked for
+/// a decoded payload.
+pub struct MockResponse {
+  pub status: StatusCode,
+  pub body: String,
+  pub headers: Vec<(String, String)>
+}
+
+impl MockResponse {
+  pub fn json(body: &str) -> MockResponse {
+    MockResponse { status: StatusCode::Ok, body: body.to_owned(), headers: Vec::new() }
+  }
+
+  pub fn status(status: StatusCode) -> MockResponse {
+    MockResponse { status: status, body: String::new(), headers: Vec::new() }
+  }
+
+  pub fn header(mut self, name: &str, value: &str) -> MockResponse {
+    self.headers.push((name.to_owned(), value.to_owned()));
+    self
+  }
+
+  fn parsed_header<T: FromStr>(&self, name: &str) -> Option<T> {
+    self
+      .headers
+      .iter()
+      .find(|&&(ref header, _)| header == name)
+      .and_then(|&(_, ref value)| value.parse().ok())
+  }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct MockRequest {
+  pub method: Method,
+  pub url: Url,
+  pub body: Option<String>
+}
+
+/// Answers requests with its responses in order and records every request
+pub struct MockClient {
+  pub requests: RefCell<Vec<MockRequest>>,
+  responses: RefCell<VecDeque<MockResponse>>
+}
+
+impl MockClient {
+  pub fn new(responses: Vec<MockResponse>) -> MockClient {
+    MockClient { requests: RefCell::new(Vec::new()), responses: RefCell::new(responses.into_iter().collect()) }
+  }
+
+  pub fn request_count(&self) -> usize {
+    self.requests.borrow().len()
+  }
+
+  fn respond(&self, method: Method, url: Url, body: Option<String>) -> MockResponse {
+    let description = format!("{} {}", method, url);
+    self.requests.borrow_mut().push(MockRequest { method: method, url: url, body: body });
+    self
+      .responses
+      .borrow_mut()
+      .pop_front()
+      .unwrap_or_else(|| panic!("no response left for {}", description))
+  }
+}
+
+fn encode<E: Encodable>(body: &E) -> String {
+  json::encode(body).unwrap()
+}
+
+fn decode<D: Decodable>(response: MockResponse) -> Result<D, GitErr> {
+  json::decode(&response.body).map_err(|err| GitErr::DecodeErr(err.to_string()))
+}
+
+impl SimpleClient for MockClient {
+  fn request_without_payload<D: Decodable>(&self, method: Method, url: Url) -> Result<D, GitErr> {
+    decode(self.respond(method, url, None))
+  }
+
+  fn request_with_payload<D: Decodable, E: Encodable>(&self, method: Method, url: Url, body: E) -> Result<D, GitErr> {
+    decode(self.respond(method, url, Some(encode(&body))))
+  }
+
+  fn request_for_status(&self, method: Method, url: Url) -> Result<StatusCode, GitErr> {
+    Ok(self.respond(method, url, None).status)
+  }
+
+  fn request_with_payload_for_status<E: Encodable>(&self, method: Method, url: Url, body: E) -> Result<StatusCode, GitErr> {
+    Ok(self.respond(method, url, Some(encode(&body))).status)
+  }
+
+  fn request_with_raw_payload<D: Decodable, R: Read>(&self, method: Method, url: Url, _content_type: Mime, body: &mut R, _length: u64) -> Result<D, GitErr> {
+    let mut raw = String::new();
+    let _ = body.read_to_string(&mut raw);
+    decode(self.respond(method, url, Some(raw)))
+  }
+
+  fn request_to_writer<W: Write>(&self, method: Method, url: Url, _accept: Mime, out: &mut W) -> Result<u64, GitErr> {
+    let response = self.respond(method, url, None);
+    out
+      .write_all(response.body.as_bytes())
+      .map(|_| response.body.len() as u64)
+      .map_err(|err| GitErr::NetworkErr(err.to_string()))
+  }
+
+  fn request_if_ready<D: Decodable + Default>(&self, method: Method, url: Url) -> Result<Option<D>, GitErr> {
+    let response = self.respond(method, url, None);
+    match response.status {
+      StatusCode::Accepted => Ok(None),
+      StatusCode::NoContent => Ok(Some(D::default())),
+      _ => decode(response).map(Some)
+    }
+  }
+
+  fn request_if_modified<D: Decodable>(&self, method: Method, url: Url, _last_modified: Option<String>) -> Result<PollResponse<D>, GitErr> {
+    let response = self.respond(method, url, None);
+    let poll_interval = response.parsed_header("X-Poll-Interval").map(Duration::from_secs);
+    let last_modified = response.parsed_header("Last-Modified");
+    let content = match response.status {
+      StatusCode::NotModified => None,
+      _ => Some(try!(decode(response)))
+    };
+    Ok(PollResponse { content: content, poll_interval: poll_interval, last_modified: last_modified })
+  }
+
+  fn request_rate_limited<D: Decodable>(&self, method: Method, url: Url) -> Result<RateLimitedResponse<D>, GitErr> {
+    let response = self.respond(method, url, None);
+    let remaining = response.parsed_header("X-RateLimit-Remaining");
+    let reset = response.parsed_header("X-RateLimit-Reset");
+    let retry_after = response.parsed_header("Retry-After").map(Duration::from_secs);
+    let content = match response.status {
+      StatusCode::Forbidden | StatusCode::TooManyRequests => None,
+      _ => Some(try!(decode(response)))
+    };
+    Ok(RateLimitedResponse { content: content, remaining: remaining, reset: reset, retry_after: retry_after })
+  }
+}
+
+fn url_fields(base: &str, names: &[&str]) -> String {
+  names
+    .iter()
+    .map(|name| format!(r#""{}": "{}/{}""#, name, base, name))
+    .collect::<Vec<_>>()
+    .join(",\n")
+}
+
+/// A public repository on master, with issues and without a wiki, as Github
+/// returns it
+pub fn repo_json(owner: &str, name: &str) -> String {
+  let owner_urls = url_fields(&format!("https://api.github.com/users/{}", owner), &[
+    "avatar_url", "html_url", "followers_url", "following_url", "gists_url", "starred_url",
+    "subscriptions_url", "organizations_url", "repos_url", "events_url", "received_events_url",
+  ]);
+  let repo_urls = url_fields(&format!("https://api.github.com/repos/{}/{}", owner, name), &[
+    "url", "html_url", "archive_url", "assignees_url", "blobs_url", "branches_url", "clone_url",
+    "collaborators_url", "comments_url", "commits_url", "compare_url", "contents_url",
+    "contributors_url", "downloads_url", "events_url", "forks_url", "git_commits_url",
+    "git_refs_url", "git_tags_url", "git_url", "hooks_url", "issue_comment_url",
+    "issue_events_url", "issues_url", "keys_url", "labels_url", "languages_url", "merges_url",
+    "milestones_url", "notifications_url", "pulls_url", "releases_url", "ssh_url",
+    "stargazers_url", "statuses_url", "subscribers_url", "subscription_url", "svn_url",
+    "tags_url", "teams_url", "trees_url",
+  ]);
+  format!(r#"{{
+    "id": 1,
+    "owner": {{ "login": "{}", "id": 2, "gravatar_id": "", "site_admin": false, {} }},
+    "name": "{}",
+    "full_name": "{}/{}",
+    "private": false,
+    "fork": false,
+    "forks_count": 0,
+    "stargazers_count": 0,
+    "watchers_count": 0,
+    "size": 0,
+    "default_branch": "master",
+    "open_issues_count": 0,
+    "has_issues": true,
+    "has_wiki": false,
+    "has_pages": false,
+    "has_downloads": true,
+    "pushed_at": "2020-01-01T00:00:00Z",
+    "created_at": "2020-01-01T00:00:00Z",
+    "updated_at": "2020-01-01T00:00:00Z",
+    {}
+  }}"#, owner, owner_urls, name, owner, name, repo_urls)
+}
@@ -0,0 +1,151 @@
+use rustc_serialize::{
+  Encodable,
+  Encoder,
+};
+
+use types::{
+  BranchName,
+  GitErr,
+  OrganizationName,
+};
+
+use types::branch_protection::StatusCheckContext;
+use types::repos::Repository;
+
+/// The settings every repository of an organization is expected to have.
+/// A None setting is not audited.
+#[derive(RustcEncodable, RustcDecodable, Debug, PartialEq, Clone)]
+pub struct RepoPolicy {
+  pub default_branch: Option<BranchName>,
+  pub private: Option<bool>,
+  pub has_issues: Option<bool>,
+  pub has_wiki: Option<bool>,
+  pub require_protection: bool,
+  pub required_status_checks: Vec<StatusCheckContext>,
+  pub required_approving_review_count: Option<u32>,
+  pub enforce_admins: Option<bool>,
+  pub required_signatures: Option<bool>,
+  pub skip_archived: bool
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum PolicyDeviation {
+  DefaultBranch { expected: BranchName, actual: BranchName },
+  Visibility { expected_private: bool, actual_private: bool },
+  HasIssues { expected: bool, actual: bool },
+  HasWiki { expected: bool, actual: bool },
+  UnprotectedBranch { branch: BranchName },
+  MissingStatusChecks { branch: BranchName, missing: Vec<StatusCheckContext> },
+  ApprovingReviewCount { branch: BranchName, expected: u32, actual: Option<u32> },
+  EnforceAdmins { branch: BranchName, expected: bool, actual: bool },
+  RequiredSignatures { branch: BranchName, expected: bool, actual: bool },
+  Unreadable { err: GitErr },
+}
+
+// Encoded as an object of named fields tagged with its "kind", e.g.
+// {"kind":"missing_status_checks","branch":"master","missing":["ci"]}
+impl Encodable for PolicyDeviation {
+  fn encode<S: Encoder>(&self, s: &mut S) -> Result<(), S::Error> {
+    match *self {
+      PolicyDeviation::DefaultBranch { ref expected, ref actual } => {
+        s.emit_struct("PolicyDeviation", 3, |s| {
+          try!(s.emit_struct_field("kind", 0, |s| "default_branch".encode(s)));
+          try!(s.emit_struct_field("expected", 1, |s| expected.encode(s)));
+          s.emit_struct_field("actual", 2, |s| actual.encode(s))
+        })
+      },
+      PolicyDeviation::Visibility { ref expected_private, ref actual_private } => {
+        s.emit_struct("PolicyDeviation", 3, |s| {
+          try!(s.emit_struct_field("kind", 0, |s| "visibility".encode(s)));
+          try!(s.emit_struct_field("expected_private", 1, |s| expected_private.encode(s)));
+          s.emit_struct_field("actual_private", 2, |s| actual_private.encode(s))
+        })
+      },
+      PolicyDeviation::HasIssues { ref expected, ref actual } => {
+        s.emit_struct("PolicyDeviation", 3, |s| {
+          try!(s.emit_struct_field("kind", 0, |s| "has_issues".encode(s)));
+          try!(s.emit_struct_field("expected", 1, |s| expected.encode(s)));
+          s.emit_struct_field("actual", 2, |s| actual.encode(s))
+        })
+      },
+      PolicyDeviation::HasWiki { ref expected, ref actual } => {
+        s.emit_struct("PolicyDeviation", 3, |s| {
+          try!(s.emit_struct_field("kind", 0, |s| "has_wiki".encode(s)));
+          try!(s.emit_struct_field("expected", 1, |s| expected.encode(s)));
+          s.emit_struct_field("actual", 2, |s| actual.encode(s))
+        })
+      },
+      PolicyDeviation::UnprotectedBranch { ref branch } => {
+        s.emit_struct("PolicyDeviation", 2, |s| {
+          try!(s.emit_struct_field("kind", 0, |s| "unprotected_branch".encode(s)));
+          s.emit_struct_field("branch", 1, |s| branch.encode(s))
+        })
+      },
+      PolicyDeviation::MissingStatusChecks { ref branch, ref missing } => {
+        s.emit_struct("PolicyDeviation", 3, |s| {
+          try!(s.emit_struct_field("kind", 0, |s| "missing_status_checks".encode(s)));
+          try!(s.emit_struct_field("branch", 1, |s| branch.encode(s)));
+          s.emit_struct_field("missing", 2, |s| missing.encode(s))
+        })
+      },
+      PolicyDeviation::ApprovingReviewCount { ref branch, ref expected, ref actual } => {
+        s.emit_struct("PolicyDeviation", 4, |s| {
+          try!(s.emit_struct_field("kind", 0, |s| "approving_review_count".encode(s)));
+          try!(s.emit_struct_field("branch", 1, |s| branch.encode(s)));
+          try!(s.emit_struct_field("expected", 2, |s| expected.encode(s)));
+          s.emit_struct_field("actual", 3, |s| actual.encode(s))
+        })
+      },
+      PolicyDeviation::EnforceAdmins { ref branch, ref expected, ref actual } => {
+        s.emit_struct("PolicyDeviation", 4, |s| {
+          try!(s.emit_struct_field("kind", 0, |s| "enforce_admins".encode(s)));
+          try!(s.emit_struct_field("branch", 1, |s| branch.encode(s)));
+          try!(s.emit_struct_field("expected", 2, |s| expected.encode(s)));
+          s.emit_struct_field("actual", 3, |s| actual.encode(s))
+        })
+      },
+      PolicyDeviation::RequiredSignatures { ref branch, ref expected, ref actual } => {
+        s.emit_struct("PolicyDeviation", 4, |s| {
+          try!(s.emit_struct_field("kind", 0, |s| "required_signatures".encode(s)));
+          try!(s.emit_struct_field("branch", 1, |s| branch.encode(s)));
+          try!(s.emit_struct_field("expected", 2, |s| expected.encode(s)));
+          s.emit_struct_field("actual", 3, |s| actual.encode(s))
+        })
+      },
+      PolicyDeviation::Unreadable { ref err } => {
+        s.emit_struct("PolicyDeviation", 2, |s| {
+          try!(s.emit_struct_field("kind", 0, |s| "unreadable".encode(s)));
+          s.emit_struct_field("err", 1, |s| err.encode(s))
+        })
+      },
+    }
+  }
+}
+
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct RepoAuditReport {
+  pub repo: Repository,
+  pub deviations: Vec<PolicyDeviation>
+}
+
+impl RepoAuditReport {
+  pub fn is_compliant(&self) -> bool {
+    self.deviations.is_empty()
+  }
+}
+
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct OrgAuditReport {
+  pub org: OrganizationName,
+  pub repos: Vec<RepoAuditReport>
+}
+
+impl OrgAuditReport {
+  pub fn is_compliant(&self) -> bool {
+    self.repos.iter().all(|report| report.is_compliant())
+  }
+
+  pub fn non_compliant(&self) -> Vec<&RepoAuditReport> {
+    self.repos.iter().filter(|report| !report.is_compliant()).collect()
+  }
+}
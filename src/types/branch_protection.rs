@@ -0,0 +1,98 @@
+use types::Url;
+
+use types::users::{
+  GithubUser,
+  UserName,
+};
+
+use types::teams::TeamSummary;
+
+pub use types::teams::TeamSlug;
+
+pub type StatusCheckContext = String;
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct ProtectionFlag {
+  pub url: Option<Url>,
+  pub enabled: bool
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct RequiredStatusChecks {
+  pub url: Option<Url>,
+  pub strict: bool,
+  pub contexts: Vec<StatusCheckContext>
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct DismissalRestrictions {
+  pub users: Vec<GithubUser>,
+  pub teams: Vec<TeamSummary>
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct RequiredPullRequestReviews {
+  pub url: Option<Url>,
+  pub dismiss_stale_reviews: bool,
+  pub require_code_owner_reviews: bool,
+  pub required_approving_review_count: Option<u32>,
+  pub dismissal_restrictions: Option<DismissalRestrictions>
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct BranchRestrictions {
+  pub url: Option<Url>,
+  pub users: Vec<GithubUser>,
+  pub teams: Vec<TeamSummary>
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct BranchProtection {
+  pub url: Option<Url>,
+  pub required_status_checks: Option<RequiredStatusChecks>,
+  pub enforce_admins: Option<ProtectionFlag>,
+  pub required_pull_request_reviews: Option<RequiredPullRequestReviews>,
+  pub restrictions: Option<BranchRestrictions>,
+  pub required_signatures: Option<ProtectionFlag>,
+  pub required_linear_history: Option<ProtectionFlag>,
+  pub allow_force_pushes: Option<ProtectionFlag>,
+  pub allow_deletions: Option<ProtectionFlag>
+}
+
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct UpdateRequiredStatusChecks {
+  pub strict: bool,
+  pub contexts: Vec<StatusCheckContext>
+}
+
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct UpdateDismissalRestrictions {
+  pub users: Vec<UserName>,
+  pub teams: Vec<TeamSlug>
+}
+
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct UpdateRequiredPullRequestReviews {
+  pub dismiss_stale_reviews: Option<bool>,
+  pub require_code_owner_reviews: Option<bool>,
+  pub required_approving_review_count: Option<u32>,
+  pub dismissal_restrictions: Option<UpdateDismissalRestrictions>
+}
+
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct UpdateBranchRestrictions {
+  pub users: Vec<UserName>,
+  pub teams: Vec<TeamSlug>
+}
+
+/// Replaces the whole protection of a branch. A None rule is disabled.
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct UpdateBranchProtection {
+  pub required_status_checks: Option<UpdateRequiredStatusChecks>,
+  pub enforce_admins: Option<bool>,
+  pub required_pull_request_reviews: Option<UpdateRequiredPullRequestReviews>,
+  pub restrictions: Option<UpdateBranchRestrictions>,
+  pub required_linear_history: Option<bool>,
+  pub allow_force_pushes: Option<bool>,
+  pub allow_deletions: Option<bool>
+}
@@ -0,0 +1,364 @@
+use rustc_serialize::{
+  Decodable,
+  Decoder,
+  Encodable,
+  Encoder,
+};
+
+use types::{
+  Url,
+  Sha,
+  GitTm,
+  Filename,
+  BranchName,
+  Message,
+};
+
+pub type CheckRunId = u64;
+pub type CheckSuiteId = u64;
+pub type AppId = u64;
+pub type CheckName = String;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum CheckStatus {
+  Queued,
+  InProgress,
+  Completed,
+  Waiting,
+  Requested,
+  Pending,
+}
+
+custom_enum_decode_encode!(
+  CheckStatus [
+    "queued" <=> [CheckStatus::Queued],
+    "in_progress" <=> [CheckStatus::InProgress],
+    "completed" <=> [CheckStatus::Completed],
+    "waiting" <=> [CheckStatus::Waiting],
+    "requested" <=> [CheckStatus::Requested],
+    "pending" <=> [CheckStatus::Pending],
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum CheckConclusion {
+  ActionRequired,
+  Cancelled,
+  Failure,
+  Neutral,
+  Success,
+  Skipped,
+  Stale,
+  TimedOut,
+}
+
+custom_enum_decode_encode!(
+  CheckConclusion [
+    "action_required" <=> [CheckConclusion::ActionRequired],
+    "cancelled" <=> [CheckConclusion::Cancelled],
+    "failure" <=> [CheckConclusion::Failure],
+    "neutral" <=> [CheckConclusion::Neutral],
+    "success" <=> [CheckConclusion::Success],
+    "skipped" <=> [CheckConclusion::Skipped],
+    "stale" <=> [CheckConclusion::Stale],
+    "timed_out" <=> [CheckConclusion::TimedOut],
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum AnnotationLevel {
+  Notice,
+  Warning,
+  Failure,
+}
+
+custom_enum_decode_encode!(
+  AnnotationLevel [
+    "notice" <=> [AnnotationLevel::Notice],
+    "warning" <=> [AnnotationLevel::Warning],
+    "failure" <=> [AnnotationLevel::Failure],
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum CheckRunFilter {
+  Latest,
+  All,
+}
+
+custom_enum_decode_encode!(
+  CheckRunFilter [
+    "latest" <=> [CheckRunFilter::Latest],
+    "all" <=> [CheckRunFilter::All],
+  ]
+);
+
+/// An annotation sent with a check run output or listed from one. Only
+/// listed annotations carry a `blob_href`.
+#[derive(RustcDecodable, Debug, PartialEq, Clone)]
+pub struct CheckAnnotation {
+  pub path: Filename,
+  pub start_line: u32,
+  pub end_line: u32,
+  pub start_column: Option<u32>,
+  pub end_column: Option<u32>,
+  pub annotation_level: AnnotationLevel,
+  pub message: Message,
+  pub title: Option<String>,
+  pub raw_details: Option<String>,
+  pub blob_href: Option<Url>
+}
+
+// Custom encode, as Github rejects the read-only blob_href
+impl Encodable for CheckAnnotation {
+  fn encode<S: Encoder>(&self, s: &mut S) -> Result<(), S::Error> {
+    s.emit_struct("CheckAnnotation", 9, |s| {
+      try!(s.emit_struct_field("path", 0, |s| self.path.encode(s)));
+      try!(s.emit_struct_field("start_line", 1, |s| self.start_line.encode(s)));
+      try!(s.emit_struct_field("end_line", 2, |s| self.end_line.encode(s)));
+      try!(s.emit_struct_field("start_column", 3, |s| self.start_column.encode(s)));
+      try!(s.emit_struct_field("end_column", 4, |s| self.end_column.encode(s)));
+      try!(s.emit_struct_field("annotation_level", 5, |s| self.annotation_level.encode(s)));
+      try!(s.emit_struct_field("message", 6, |s| self.message.encode(s)));
+      try!(s.emit_struct_field("title", 7, |s| self.title.encode(s)));
+      s.emit_struct_field("raw_details", 8, |s| self.raw_details.encode(s))
+    })
+  }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CheckImage {
+  pub alt: String,
+  pub image_url: Url,
+  pub caption: Option<String>
+}
+
+optional_fields_encode!(
+  CheckImage {
+    "alt" => alt,
+    "image_url" => image_url,
+  } [
+    caption,
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CheckRunOutput {
+  pub title: String,
+  pub summary: Message,
+  pub text: Option<Message>,
+  pub annotations: Vec<CheckAnnotation>,
+  pub images: Vec<CheckImage>
+}
+
+optional_fields_encode!(
+  CheckRunOutput {
+    "title" => title,
+    "summary" => summary,
+    "annotations" => annotations,
+    "images" => images,
+  } [
+    text,
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CreateCheckRun {
+  pub name: CheckName,
+  pub head_sha: Sha,
+  pub details_url: Option<Url>,
+  pub external_id: Option<String>,
+  pub status: Option<CheckStatus>,
+  pub started_at: Option<GitTm>,
+  pub conclusion: Option<CheckConclusion>,
+  pub completed_at: Option<GitTm>,
+  pub output: Option<CheckRunOutput>
+}
+
+optional_fields_encode!(
+  CreateCheckRun {
+    "name" => name,
+    "head_sha" => head_sha,
+  } [
+    details_url,
+    external_id,
+    status,
+    started_at,
+    conclusion,
+    completed_at,
+    output,
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct UpdateCheckRun {
+  pub name: Option<CheckName>,
+  pub details_url: Option<Url>,
+  pub external_id: Option<String>,
+  pub status: Option<CheckStatus>,
+  pub started_at: Option<GitTm>,
+  pub conclusion: Option<CheckConclusion>,
+  pub completed_at: Option<GitTm>,
+  pub output: Option<CheckRunOutput>
+}
+
+optional_fields_encode!(
+  UpdateCheckRun [
+    name,
+    details_url,
+    external_id,
+    status,
+    started_at,
+    conclusion,
+    completed_at,
+    output,
+  ]
+);
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct CheckRunOutputSummary {
+  pub title: Option<String>,
+  pub summary: Option<Message>,
+  pub text: Option<Message>,
+  pub annotations_count: u32,
+  pub annotations_url: Url
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct CheckSuiteReference {
+  pub id: CheckSuiteId
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct CheckRun {
+  pub id: CheckRunId,
+  pub name: CheckName,
+  pub head_sha: Sha,
+  pub external_id: Option<String>,
+  pub url: Url,
+  pub html_url: Option<Url>,
+  pub details_url: Option<Url>,
+  pub status: CheckStatus,
+  pub conclusion: Option<CheckConclusion>,
+  pub started_at: Option<GitTm>,
+  pub completed_at: Option<GitTm>,
+  pub output: CheckRunOutputSummary,
+  pub check_suite: Option<CheckSuiteReference>
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct CheckRunList {
+  pub total_count: u32,
+  pub check_runs: Vec<CheckRun>
+}
+
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct CheckRunsQuery {
+  pub check_name: Option<CheckName>,
+  pub status: Option<CheckStatus>,
+  pub filter: Option<CheckRunFilter>
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct CheckSuite {
+  pub id: CheckSuiteId,
+  pub head_branch: Option<BranchName>,
+  pub head_sha: Sha,
+  pub status: Option<CheckStatus>,
+  pub conclusion: Option<CheckConclusion>,
+  pub url: Url,
+  pub before: Option<Sha>,
+  pub after: Option<Sha>,
+  pub latest_check_runs_count: u32,
+  pub check_runs_url: Url,
+  pub created_at: GitTm,
+  pub updated_at: GitTm
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct CheckSuiteList {
+  pub total_count: u32,
+  pub check_suites: Vec<CheckSuite>
+}
+
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct CreateCheckSuite {
+  pub head_sha: Sha
+}
+
+#[derive(RustcEncodable, RustcDecodable, Debug, PartialEq, Clone)]
+pub struct AutoTriggerCheck {
+  pub app_id: AppId,
+  pub setting: bool
+}
+
+#[derive(RustcEncodable, RustcDecodable, Debug, PartialEq, Clone)]
+pub struct CheckSuitePreferences {
+  pub auto_trigger_checks: Vec<AutoTriggerCheck>
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct CheckSuitePreferencesResult {
+  pub preferences: CheckSuitePreferences
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum RerequestStatus {
+  Rerequested,
+  NotRerequested
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+  use rustc_serialize::json;
+
+  use super::{
+    CheckConclusion,
+    CheckImage,
+    CheckRunOutput,
+    CheckStatus,
+    CreateCheckRun,
+    UpdateCheckRun,
+  };
+
+  #[test]
+  fn it_leaves_unset_fields_out_of_an_update() {
+    let update = UpdateCheckRun {
+      name: None,
+      details_url: None,
+      external_id: None,
+      status: Some(CheckStatus::Completed),
+      started_at: None,
+      conclusion: Some(CheckConclusion::Success),
+      completed_at: None,
+      output: None
+    };
+    expect!(json::encode(&update).unwrap()).to(be_equal_to(r#"{"status":"completed","conclusion":"success"}"#.to_owned()));
+  }
+
+  #[test]
+  fn it_leaves_unset_fields_out_of_a_new_check_run() {
+    let create = CreateCheckRun {
+      name: "lint".to_owned(),
+      head_sha: "abc123".to_owned(),
+      details_url: None,
+      external_id: None,
+      status: None,
+      started_at: None,
+      conclusion: None,
+      completed_at: None,
+      output: None
+    };
+    expect!(json::encode(&create).unwrap()).to(be_equal_to(r#"{"name":"lint","head_sha":"abc123"}"#.to_owned()));
+  }
+
+  #[test]
+  fn it_leaves_unset_text_and_captions_out_of_the_output() {
+    let image = CheckImage { alt: "graph".to_owned(), image_url: "https://example.com/graph.png".to_owned(), caption: None };
+    let output = CheckRunOutput { title: "Lint".to_owned(), summary: "All good".to_owned(), text: None, annotations: vec![], images: vec![image] };
+    let expected = r#"{"title":"Lint","summary":"All good","annotations":[],"images":[{"alt":"graph","image_url":"https://example.com/graph.png"}]}"#;
+    expect!(json::encode(&output).unwrap()).to(be_equal_to(expected.to_owned()));
+  }
+}
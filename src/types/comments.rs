@@ -15,6 +15,7 @@ use types::{
 };
 
 use types::users::GithubUser;
+use types::reactions::ReactionRollup;
 use types::repos::Repo;
 use types::pull_requests::PullRequest;
 
@@ -35,7 +36,8 @@ pub struct PullRequestComment {
   pub created_at: GitTm,
   pub updated_at: GitTm,
   pub html_url: Url,
-  pub pull_request_url: Url
+  pub pull_request_url: Url,
+  pub reactions: Option<ReactionRollup>
   // TODO: _links
 }
 
@@ -137,9 +139,9 @@ custom_enum_decode_encode!(
 
 #[derive(RustcEncodable, RustcDecodable, Debug, PartialEq, Clone)]
 pub struct ListRepoCommentsQuery {
-  pub sort: CommentSortables,
-  pub direction: SortDirection,
-  pub since: GitTm
+  pub sort: Option<CommentSortables>,
+  pub direction: Option<SortDirection>,
+  pub since: Option<GitTm>
 }
 
 #[derive(RustcEncodable, RustcDecodable, Debug, PartialEq, Clone)]
@@ -147,8 +149,7 @@ pub struct CreateIssueComment {
   pub body: Message
 }
 
-#[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum DeleteCommentStatus {
   Deleted,
   NotDeleted
@@ -162,5 +163,6 @@ pub struct IssueComment {
   pub body: Message,
   pub user: GithubUser,
   pub created_at: GitTm,
-  pub updated_at: GitTm
+  pub updated_at: GitTm,
+  pub reactions: Option<ReactionRollup>
 }
@@ -1,15 +1,24 @@
+use rustc_serialize::{
+  Decodable,
+  Decoder,
+  Encodable,
+  Encoder,
+};
+
 use types::{
   BranchName,
   Sha,
   Message,
   GitTm,
+  GitRef,
   Filename,
   Url,
 };
 
 use types::users::{
   GithubUser,
-  GitUser
+  GitUser,
+  UserName
 };
 
 use types::repos::Repo;
@@ -72,3 +81,136 @@ pub struct PushCommit {
   pub modified: Vec<Filename>,
 }
 
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct CommitIdentity {
+  pub name: String,
+  pub email: String
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct GitCommit {
+  pub sha: Sha,
+  pub url: Url,
+  pub html_url: Option<Url>,
+  pub author: CommitAuthor,
+  pub committer: CommitAuthor,
+  pub message: Message,
+  pub tree: CommitTreeNode,
+  pub parents: Vec<CommitTreeNode>
+}
+
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct CommitsQuery {
+  pub sha: Option<GitRef>,
+  pub path: Option<Filename>,
+  pub author: Option<UserName>,
+  pub since: Option<GitTm>,
+  pub until: Option<GitTm>
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct CommitStats {
+  pub additions: u32,
+  pub deletions: u32,
+  pub total: u32
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum CommitFileStatus {
+  Added,
+  Removed,
+  Modified,
+  Renamed,
+  Copied,
+  Changed,
+  Unchanged,
+}
+
+custom_enum_decode_encode!(
+  CommitFileStatus [
+    "added" <=> [CommitFileStatus::Added],
+    "removed" <=> [CommitFileStatus::Removed],
+    "modified" <=> [CommitFileStatus::Modified],
+    "renamed" <=> [CommitFileStatus::Renamed],
+    "copied" <=> [CommitFileStatus::Copied],
+    "changed" <=> [CommitFileStatus::Changed],
+    "unchanged" <=> [CommitFileStatus::Unchanged],
+  ]
+);
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct CommitFile {
+  pub sha: Option<Sha>,
+  pub filename: Filename,
+  pub previous_filename: Option<Filename>,
+  pub status: CommitFileStatus,
+  pub additions: u32,
+  pub deletions: u32,
+  pub changes: u32,
+  pub blob_url: Option<Url>,
+  pub raw_url: Option<Url>,
+  pub contents_url: Option<Url>,
+  pub patch: Option<String>
+}
+
+/// A commit listed from a repository's history. Commits whose author or
+/// committer has no Github account carry no user.
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct RepoCommit {
+  pub url: Url,
+  pub sha: Sha,
+  pub html_url: Url,
+  pub comments_url: Url,
+  pub commit: GithubCommitSummary,
+  pub author: Option<GithubUser>,
+  pub committer: Option<GithubUser>,
+  pub parents: Vec<CommitTreeNode>
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct FullCommit {
+  pub url: Url,
+  pub sha: Sha,
+  pub html_url: Url,
+  pub comments_url: Url,
+  pub commit: GithubCommitSummary,
+  pub author: Option<GithubUser>,
+  pub committer: Option<GithubUser>,
+  pub parents: Vec<CommitTreeNode>,
+  pub stats: CommitStats,
+  pub files: Vec<CommitFile>
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ComparisonStatus {
+  Diverged,
+  Ahead,
+  Behind,
+  Identical,
+}
+
+custom_enum_decode_encode!(
+  ComparisonStatus [
+    "diverged" <=> [ComparisonStatus::Diverged],
+    "ahead" <=> [ComparisonStatus::Ahead],
+    "behind" <=> [ComparisonStatus::Behind],
+    "identical" <=> [ComparisonStatus::Identical],
+  ]
+);
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct Comparison {
+  pub url: Url,
+  pub html_url: Url,
+  pub permalink_url: Url,
+  pub diff_url: Url,
+  pub patch_url: Url,
+  pub base_commit: RepoCommit,
+  pub merge_base_commit: RepoCommit,
+  pub status: ComparisonStatus,
+  pub ahead_by: u32,
+  pub behind_by: u32,
+  pub total_commits: u32,
+  pub commits: Vec<RepoCommit>,
+  pub files: Option<Vec<CommitFile>>
+}
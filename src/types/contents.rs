@@ -0,0 +1,252 @@
+use rustc_serialize::{
+  Decodable,
+  Decoder,
+  Encodable,
+  Encoder,
+};
+use rustc_serialize::base64::{
+  FromBase64,
+  ToBase64,
+  STANDARD,
+};
+
+use types::{
+  Url,
+  Sha,
+  Message,
+  Filename,
+  BranchName,
+  GitRef,
+  GitErr,
+};
+
+use types::commits::{
+  CommitIdentity,
+  GitCommit,
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ContentType {
+  File,
+  Dir,
+  Symlink,
+  Submodule,
+}
+
+custom_enum_decode_encode!(
+  ContentType [
+    "file" <=> [ContentType::File],
+    "dir" <=> [ContentType::Dir],
+    "symlink" <=> [ContentType::Symlink],
+    "submodule" <=> [ContentType::Submodule],
+  ]
+);
+
+#[derive(Debug, Clone)]
+pub struct Content {
+  pub content_type: ContentType,
+  pub encoding: Option<String>,
+  pub size: u32,
+  pub name: Filename,
+  pub path: Filename,
+  pub content: Option<String>,
+  pub sha: Sha,
+  pub url: Url,
+  pub git_url: Option<Url>,
+  pub html_url: Option<Url>,
+  pub download_url: Option<Url>,
+  pub target: Option<Filename>,
+  pub submodule_git_url: Option<Url>,
+}
+
+// Custom decode for the reserved word "type"
+impl Decodable for Content {
+  fn decode<D: Decoder>(d: &mut D) -> Result<Content, D::Error> {
+    d.read_struct("Content", 13, |d| {
+      Ok(Content {
+        content_type: try!(d.read_struct_field("type", 0, Decodable::decode)),
+        encoding: try!(d.read_struct_field("encoding", 1, Decodable::decode)),
+        size: try!(d.read_struct_field("size", 2, Decodable::decode)),
+        name: try!(d.read_struct_field("name", 3, Decodable::decode)),
+        path: try!(d.read_struct_field("path", 4, Decodable::decode)),
+        content: try!(d.read_struct_field("content", 5, Decodable::decode)),
+        sha: try!(d.read_struct_field("sha", 6, Decodable::decode)),
+        url: try!(d.read_struct_field("url", 7, Decodable::decode)),
+        git_url: try!(d.read_struct_field("git_url", 8, Decodable::decode)),
+        html_url: try!(d.read_struct_field("html_url", 9, Decodable::decode)),
+        download_url: try!(d.read_struct_field("download_url", 10, Decodable::decode)),
+        target: try!(d.read_struct_field("target", 11, Decodable::decode)),
+        submodule_git_url: try!(d.read_struct_field("submodule_git_url", 12, Decodable::decode)),
+      })
+    })
+  }
+}
+
+impl Content {
+  /// Decodes the base64 body of a file. Directory listings, symlinks and
+  /// submodules carry no body and yield a DecodeErr.
+  pub fn decoded_content(&self) -> Result<Vec<u8>, GitErr> {
+    match self.content {
+      Some(ref content) => content.from_base64().map_err(|err| GitErr::DecodeErr(err.to_string())),
+      None => Err(GitErr::DecodeErr("no content for ".to_owned() + &self.path))
+    }
+  }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ContentsQuery {
+  pub git_ref: Option<GitRef>
+}
+
+// Custom encode for the reserved word "ref"
+impl Encodable for ContentsQuery {
+  fn encode<S: Encoder>(&self, s: &mut S) -> Result<(), S::Error> {
+    s.emit_struct("ContentsQuery", 1, |s| {
+      s.emit_struct_field("ref", 0, |s| self.git_ref.encode(s))
+    })
+  }
+}
+
+/// Raw file contents, base64 encoded on the wire.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FileContent(pub Vec<u8>);
+
+impl Encodable for FileContent {
+  fn encode<S: Encoder>(&self, s: &mut S) -> Result<(), S::Error> {
+    let &FileContent(ref bytes) = self;
+    s.emit_str(&bytes.to_base64(STANDARD))
+  }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CreateFile {
+  pub message: Message,
+  pub content: FileContent,
+  pub branch: Option<BranchName>,
+  pub committer: Option<CommitIdentity>,
+  pub author: Option<CommitIdentity>
+}
+
+optional_fields_encode!(
+  CreateFile {
+    "message" => message,
+    "content" => content,
+  } [
+    branch,
+    committer,
+    author,
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct UpdateFile {
+  pub message: Message,
+  pub content: FileContent,
+  pub sha: Sha,
+  pub branch: Option<BranchName>,
+  pub committer: Option<CommitIdentity>,
+  pub author: Option<CommitIdentity>
+}
+
+optional_fields_encode!(
+  UpdateFile {
+    "message" => message,
+    "content" => content,
+    "sha" => sha,
+  } [
+    branch,
+    committer,
+    author,
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct DeleteFile {
+  pub message: Message,
+  pub sha: Sha,
+  pub branch: Option<BranchName>,
+  pub committer: Option<CommitIdentity>,
+  pub author: Option<CommitIdentity>
+}
+
+optional_fields_encode!(
+  DeleteFile {
+    "message" => message,
+    "sha" => sha,
+  } [
+    branch,
+    committer,
+    author,
+  ]
+);
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct FileCommit {
+  pub content: Option<Content>,
+  pub commit: GitCommit
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+  use rustc_serialize::json;
+
+  use types::commits::CommitIdentity;
+
+  use super::{
+    Content,
+    CreateFile,
+    UpdateFile,
+    DeleteFile,
+    FileContent,
+  };
+
+  fn file_with_content(content: &str) -> String {
+    r#"{
+      "type": "file",
+      "encoding": "base64",
+      "size": 11,
+      "name": "hello.txt",
+      "path": "hello.txt",
+      "content": ""#.to_owned() + content + r#"",
+      "sha": "abc123",
+      "url": "https://api.github.com/repos/test_owner/test_repo/contents/hello.txt",
+      "git_url": null,
+      "html_url": null,
+      "download_url": null
+    }"#
+  }
+
+  #[test]
+  fn it_decodes_content_with_embedded_newlines() {
+    let content: Content = json::decode(&file_with_content(r"aGVsbG8g\nd29ybGQ=\n")).unwrap();
+    expect!(content.decoded_content().unwrap()).to(be_equal_to(b"hello world".to_vec()));
+  }
+
+  #[test]
+  fn it_fails_to_decode_missing_content() {
+    let mut content: Content = json::decode(&file_with_content("")).unwrap();
+    content.content = None;
+    expect!(content.decoded_content().is_err()).to(be_equal_to(true));
+  }
+
+  #[test]
+  fn it_leaves_unset_file_fields_out_of_the_body() {
+    let create = CreateFile { message: "Add hello".to_owned(), content: FileContent(b"hello".to_vec()), branch: None, committer: None, author: None };
+    expect!(json::encode(&create).unwrap()).to(be_equal_to(r#"{"message":"Add hello","content":"aGVsbG8="}"#.to_owned()));
+
+    let update = UpdateFile { message: "Update hello".to_owned(), content: FileContent(b"hello".to_vec()), sha: "abc123".to_owned(), branch: Some("main".to_owned()), committer: None, author: None };
+    expect!(json::encode(&update).unwrap()).to(be_equal_to(r#"{"message":"Update hello","content":"aGVsbG8=","sha":"abc123","branch":"main"}"#.to_owned()));
+
+    let delete = DeleteFile { message: "Remove hello".to_owned(), sha: "abc123".to_owned(), branch: None, committer: None, author: None };
+    expect!(json::encode(&delete).unwrap()).to(be_equal_to(r#"{"message":"Remove hello","sha":"abc123"}"#.to_owned()));
+  }
+
+  #[test]
+  fn it_sends_a_set_committer() {
+    let committer = CommitIdentity { name: "Monalisa Octocat".to_owned(), email: "octocat@github.com".to_owned() };
+    let delete = DeleteFile { message: "Remove hello".to_owned(), sha: "abc123".to_owned(), branch: None, committer: Some(committer), author: None };
+    expect!(json::encode(&delete).unwrap()).to(be_equal_to(r#"{"message":"Remove hello","sha":"abc123","committer":{"name":"Monalisa Octocat","email":"octocat@github.com"}}"#.to_owned()));
+  }
+}
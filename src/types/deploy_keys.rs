@@ -0,0 +1,57 @@
+use rustc_serialize::{
+  Encodable,
+  Encoder,
+};
+
+use types::{
+  Url,
+  GitTm,
+};
+
+pub type DeployKeyId = u64;
+pub type PublicKey = String;
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct DeployKey {
+  pub id: DeployKeyId,
+  pub key: PublicKey,
+  pub url: Url,
+  pub title: String,
+  pub verified: bool,
+  pub created_at: GitTm,
+  pub read_only: bool,
+  pub added_by: Option<String>,
+  pub last_used: Option<GitTm>
+}
+
+/// A key without `read_only` set is granted write access
+#[derive(Debug, PartialEq, Clone)]
+pub struct CreateDeployKey {
+  pub title: Option<String>,
+  pub key: PublicKey,
+  pub read_only: Option<bool>
+}
+
+optional_fields_encode!(
+  CreateDeployKey {
+    "key" => key,
+  } [
+    title,
+    read_only,
+  ]
+);
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+  use rustc_serialize::json;
+
+  use super::CreateDeployKey;
+
+  #[test]
+  fn it_leaves_an_unset_title_and_read_only_out_of_the_body() {
+    let key = CreateDeployKey { title: None, key: "ssh-rsa AAAA".to_owned(), read_only: None };
+    expect!(json::encode(&key).unwrap()).to(be_equal_to(r#"{"key":"ssh-rsa AAAA"}"#.to_owned()));
+  }
+}
@@ -0,0 +1,252 @@
+use rustc_serialize::{
+  Decodable,
+  Decoder,
+  Encodable,
+  Encoder,
+};
+use rustc_serialize::json::Json;
+
+use types::{
+  Url,
+  Sha,
+  GitTm,
+  GitRef,
+};
+
+use types::statuses::StatusContext;
+use types::users::GithubUser;
+
+pub type DeploymentId = u64;
+pub type DeploymentStatusId = u64;
+pub type EnvironmentName = String;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum DeploymentState {
+  Error,
+  Failure,
+  Inactive,
+  InProgress,
+  Queued,
+  Pending,
+  Success,
+}
+
+custom_enum_decode_encode!(
+  DeploymentState [
+    "error" <=> [DeploymentState::Error],
+    "failure" <=> [DeploymentState::Failure],
+    "inactive" <=> [DeploymentState::Inactive],
+    "in_progress" <=> [DeploymentState::InProgress],
+    "queued" <=> [DeploymentState::Queued],
+    "pending" <=> [DeploymentState::Pending],
+    "success" <=> [DeploymentState::Success],
+  ]
+);
+
+#[derive(Debug, Clone)]
+pub struct Deployment {
+  pub id: DeploymentId,
+  pub url: Url,
+  pub sha: Sha,
+  pub git_ref: GitRef,
+  pub task: String,
+  pub environment: EnvironmentName,
+  pub original_environment: Option<EnvironmentName>,
+  pub description: Option<String>,
+  pub creator: Option<GithubUser>,
+  pub created_at: GitTm,
+  pub updated_at: GitTm,
+  pub statuses_url: Url,
+  pub repository_url: Url,
+  pub transient_environment: Option<bool>,
+  pub production_environment: Option<bool>,
+}
+
+// Custom decode for the reserved word "ref"
+impl Decodable for Deployment {
+  fn decode<D: Decoder>(d: &mut D) -> Result<Deployment, D::Error> {
+    d.read_struct("Deployment", 15, |d| {
+      Ok(Deployment {
+        id: try!(d.read_struct_field("id", 0, Decodable::decode)),
+        url: try!(d.read_struct_field("url", 1, Decodable::decode)),
+        sha: try!(d.read_struct_field("sha", 2, Decodable::decode)),
+        git_ref: try!(d.read_struct_field("ref", 3, Decodable::decode)),
+        task: try!(d.read_struct_field("task", 4, Decodable::decode)),
+        environment: try!(d.read_struct_field("environment", 5, Decodable::decode)),
+        original_environment: try!(d.read_struct_field("original_environment", 6, Decodable::decode)),
+        description: try!(d.read_struct_field("description", 7, Decodable::decode)),
+        creator: try!(d.read_struct_field("creator", 8, Decodable::decode)),
+        created_at: try!(d.read_struct_field("created_at", 9, Decodable::decode)),
+        updated_at: try!(d.read_struct_field("updated_at", 10, Decodable::decode)),
+        statuses_url: try!(d.read_struct_field("statuses_url", 11, Decodable::decode)),
+        repository_url: try!(d.read_struct_field("repository_url", 12, Decodable::decode)),
+        transient_environment: try!(d.read_struct_field("transient_environment", 13, Decodable::decode)),
+        production_environment: try!(d.read_struct_field("production_environment", 14, Decodable::decode)),
+      })
+    })
+  }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CreateDeployment {
+  pub git_ref: GitRef,
+  pub task: Option<String>,
+  pub auto_merge: Option<bool>,
+  pub required_contexts: Option<Vec<StatusContext>>,
+  pub payload: Option<Json>,
+  pub environment: Option<EnvironmentName>,
+  pub description: Option<String>,
+  pub transient_environment: Option<bool>,
+  pub production_environment: Option<bool>,
+}
+
+// Sent under the reserved word "ref". Unset fields are left out, as an
+// explicit null required_contexts is not the same as the default of all
+// contexts.
+optional_fields_encode!(
+  CreateDeployment {
+    "ref" => git_ref,
+  } [
+    task,
+    auto_merge,
+    required_contexts,
+    payload,
+    environment,
+    description,
+    transient_environment,
+    production_environment,
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct DeploymentsQuery {
+  pub sha: Option<Sha>,
+  pub git_ref: Option<GitRef>,
+  pub task: Option<String>,
+  pub environment: Option<EnvironmentName>,
+}
+
+// Custom encode for the reserved word "ref"
+impl Encodable for DeploymentsQuery {
+  fn encode<S: Encoder>(&self, s: &mut S) -> Result<(), S::Error> {
+    s.emit_struct("DeploymentsQuery", 4, |s| {
+      try!(s.emit_struct_field("sha", 0, |s| self.sha.encode(s)));
+      try!(s.emit_struct_field("ref", 1, |s| self.git_ref.encode(s)));
+      try!(s.emit_struct_field("task", 2, |s| self.task.encode(s)));
+      s.emit_struct_field("environment", 3, |s| self.environment.encode(s))
+    })
+  }
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct DeploymentStatus {
+  pub id: DeploymentStatusId,
+  pub url: Url,
+  pub state: DeploymentState,
+  pub creator: Option<GithubUser>,
+  pub description: Option<String>,
+  pub environment: Option<EnvironmentName>,
+  pub target_url: Option<Url>,
+  pub log_url: Option<Url>,
+  pub environment_url: Option<Url>,
+  pub created_at: GitTm,
+  pub updated_at: GitTm,
+  pub deployment_url: Url,
+  pub repository_url: Url
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CreateDeploymentStatus {
+  pub state: DeploymentState,
+  pub log_url: Option<Url>,
+  pub description: Option<String>,
+  pub environment: Option<EnvironmentName>,
+  pub environment_url: Option<Url>,
+  pub auto_inactive: Option<bool>
+}
+
+optional_fields_encode!(
+  CreateDeploymentStatus {
+    "state" => state,
+  } [
+    log_url,
+    description,
+    environment,
+    environment_url,
+    auto_inactive,
+  ]
+);
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct Environment {
+  pub id: u64,
+  pub name: EnvironmentName,
+  pub url: Url,
+  pub html_url: Url,
+  pub created_at: GitTm,
+  pub updated_at: GitTm
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct EnvironmentList {
+  pub total_count: u32,
+  pub environments: Vec<Environment>
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+  use rustc_serialize::json;
+
+  use super::{
+    Deployment,
+    CreateDeployment,
+    CreateDeploymentStatus,
+    DeploymentState,
+  };
+
+  #[test]
+  fn it_sends_the_ref_and_leaves_unset_fields_out() {
+    let create = CreateDeployment {
+      git_ref: "topic-branch".to_owned(),
+      task: None,
+      auto_merge: Some(false),
+      required_contexts: Some(vec![]),
+      payload: None,
+      environment: None,
+      description: None,
+      transient_environment: None,
+      production_environment: None,
+    };
+    expect!(json::encode(&create).unwrap()).to(be_equal_to(r#"{"ref":"topic-branch","auto_merge":false,"required_contexts":[]}"#.to_owned()));
+  }
+
+  #[test]
+  fn it_leaves_unset_fields_out_of_a_deployment_status() {
+    let status = CreateDeploymentStatus { state: DeploymentState::InProgress, log_url: None, description: None, environment: None, environment_url: None, auto_inactive: None };
+    expect!(json::encode(&status).unwrap()).to(be_equal_to(r#"{"state":"in_progress"}"#.to_owned()));
+  }
+
+  #[test]
+  fn it_decodes_the_ref_of_a_deployment() {
+    let deployment: Deployment = json::decode(r#"{
+      "id": 1,
+      "url": "https://api.github.com/repos/octocat/example/deployments/1",
+      "sha": "a84d88e7554fc1fa21bcbc4efae3c782a70d2b9d",
+      "ref": "topic-branch",
+      "task": "deploy",
+      "environment": "production",
+      "original_environment": "staging",
+      "description": null,
+      "creator": null,
+      "created_at": "2012-07-20T01:19:13Z",
+      "updated_at": "2012-07-20T01:19:13Z",
+      "statuses_url": "https://api.github.com/repos/octocat/example/deployments/1/statuses",
+      "repository_url": "https://api.github.com/repos/octocat/example",
+      "transient_environment": false,
+      "production_environment": true
+    }"#).unwrap();
+    expect!(deployment.git_ref).to(be_equal_to("topic-branch".to_owned()));
+  }
+}
@@ -0,0 +1,330 @@
+use rustc_serialize::{
+  Decodable,
+  Decoder,
+  Encodable,
+  Encoder,
+};
+use rustc_serialize::base64::FromBase64;
+
+use types::{
+  Url,
+  Sha,
+  Message,
+  Filename,
+  BranchName,
+  GitRef,
+  GitErr,
+};
+
+use types::commits::{
+  CommitAuthor,
+  CommitIdentity,
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum GitObjectType {
+  Commit,
+  Tree,
+  Blob,
+  Tag,
+}
+
+custom_enum_decode_encode!(
+  GitObjectType [
+    "commit" <=> [GitObjectType::Commit],
+    "tree" <=> [GitObjectType::Tree],
+    "blob" <=> [GitObjectType::Blob],
+    "tag" <=> [GitObjectType::Tag],
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum FileMode {
+  File,
+  Executable,
+  Subdirectory,
+  Submodule,
+  Symlink,
+}
+
+custom_enum_decode_encode!(
+  FileMode [
+    "100644" <=> [FileMode::File],
+    "100755" <=> [FileMode::Executable],
+    "040000" <=> [FileMode::Subdirectory],
+    "160000" <=> [FileMode::Submodule],
+    "120000" <=> [FileMode::Symlink],
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum BlobEncoding {
+  Utf8,
+  Base64,
+}
+
+custom_enum_decode_encode!(
+  BlobEncoding [
+    "utf-8" <=> [BlobEncoding::Utf8],
+    "base64" <=> [BlobEncoding::Base64],
+  ]
+);
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct Blob {
+  pub url: Url,
+  pub sha: Sha,
+  pub size: u32,
+  pub content: String,
+  pub encoding: BlobEncoding
+}
+
+impl Blob {
+  pub fn decoded_content(&self) -> Result<Vec<u8>, GitErr> {
+    match self.encoding {
+      BlobEncoding::Base64 => self.content.from_base64().map_err(|err| GitErr::DecodeErr(err.to_string())),
+      BlobEncoding::Utf8 => Ok(self.content.clone().into_bytes())
+    }
+  }
+}
+
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct CreateBlob {
+  pub content: String,
+  pub encoding: BlobEncoding
+}
+
+#[derive(Debug, Clone)]
+pub struct TreeEntry {
+  pub path: Filename,
+  pub mode: FileMode,
+  pub object_type: GitObjectType,
+  pub size: Option<u32>,
+  pub sha: Sha,
+  pub url: Option<Url>,
+}
+
+// Custom decode for the reserved word "type"
+impl Decodable for TreeEntry {
+  fn decode<D: Decoder>(d: &mut D) -> Result<TreeEntry, D::Error> {
+    d.read_struct("TreeEntry", 6, |d| {
+      Ok(TreeEntry {
+        path: try!(d.read_struct_field("path", 0, Decodable::decode)),
+        mode: try!(d.read_struct_field("mode", 1, Decodable::decode)),
+        object_type: try!(d.read_struct_field("type", 2, Decodable::decode)),
+        size: try!(d.read_struct_field("size", 3, Decodable::decode)),
+        sha: try!(d.read_struct_field("sha", 4, Decodable::decode)),
+        url: try!(d.read_struct_field("url", 5, Decodable::decode)),
+      })
+    })
+  }
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct Tree {
+  pub sha: Sha,
+  pub url: Url,
+  pub tree: Vec<TreeEntry>,
+  pub truncated: bool
+}
+
+/// An entry of a tree being created. Exactly one of `sha` and `content` is
+/// sent; an entry with neither removes `path` from the base tree.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CreateTreeEntry {
+  pub path: Filename,
+  pub mode: FileMode,
+  pub object_type: GitObjectType,
+  pub sha: Option<Sha>,
+  pub content: Option<String>,
+}
+
+// Custom encode for the reserved word "type" and the explicit null sha
+impl Encodable for CreateTreeEntry {
+  fn encode<S: Encoder>(&self, s: &mut S) -> Result<(), S::Error> {
+    s.emit_struct("CreateTreeEntry", 4, |s| {
+      try!(s.emit_struct_field("path", 0, |s| self.path.encode(s)));
+      try!(s.emit_struct_field("mode", 1, |s| self.mode.encode(s)));
+      try!(s.emit_struct_field("type", 2, |s| self.object_type.encode(s)));
+      match self.content {
+        Some(ref content) => s.emit_struct_field("content", 3, |s| content.encode(s)),
+        None => s.emit_struct_field("sha", 3, |s| self.sha.encode(s))
+      }
+    })
+  }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CreateTree {
+  pub base_tree: Option<Sha>,
+  pub tree: Vec<CreateTreeEntry>
+}
+
+optional_fields_encode!(
+  CreateTree {
+    "tree" => tree,
+  } [
+    base_tree,
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CreateGitCommit {
+  pub message: Message,
+  pub tree: Sha,
+  pub parents: Vec<Sha>,
+  pub author: Option<CommitIdentity>,
+  pub committer: Option<CommitIdentity>
+}
+
+optional_fields_encode!(
+  CreateGitCommit {
+    "message" => message,
+    "tree" => tree,
+    "parents" => parents,
+  } [
+    author,
+    committer,
+  ]
+);
+
+#[derive(Debug, Clone)]
+pub struct GitObject {
+  pub object_type: GitObjectType,
+  pub sha: Sha,
+  pub url: Url,
+}
+
+// Custom decode for the reserved word "type"
+impl Decodable for GitObject {
+  fn decode<D: Decoder>(d: &mut D) -> Result<GitObject, D::Error> {
+    d.read_struct("GitObject", 3, |d| {
+      Ok(GitObject {
+        object_type: try!(d.read_struct_field("type", 0, Decodable::decode)),
+        sha: try!(d.read_struct_field("sha", 1, Decodable::decode)),
+        url: try!(d.read_struct_field("url", 2, Decodable::decode)),
+      })
+    })
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct Reference {
+  pub git_ref: GitRef,
+  pub url: Url,
+  pub object: GitObject,
+}
+
+// Custom decode for the reserved word "ref"
+impl Decodable for Reference {
+  fn decode<D: Decoder>(d: &mut D) -> Result<Reference, D::Error> {
+    d.read_struct("Reference", 3, |d| {
+      Ok(Reference {
+        git_ref: try!(d.read_struct_field("ref", 0, Decodable::decode)),
+        url: try!(d.read_struct_field("url", 1, Decodable::decode)),
+        object: try!(d.read_struct_field("object", 2, Decodable::decode)),
+      })
+    })
+  }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CreateReference {
+  pub git_ref: GitRef,
+  pub sha: Sha,
+}
+
+// Custom encode for the reserved word "ref"
+impl Encodable for CreateReference {
+  fn encode<S: Encoder>(&self, s: &mut S) -> Result<(), S::Error> {
+    s.emit_struct("CreateReference", 2, |s| {
+      try!(s.emit_struct_field("ref", 0, |s| self.git_ref.encode(s)));
+      s.emit_struct_field("sha", 1, |s| self.sha.encode(s))
+    })
+  }
+}
+
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct UpdateReference {
+  pub sha: Sha,
+  pub force: bool
+}
+
+pub type TagName = String;
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct AnnotatedTag {
+  pub tag: TagName,
+  pub sha: Sha,
+  pub url: Url,
+  pub message: Message,
+  pub tagger: CommitAuthor,
+  pub object: GitObject
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CreateAnnotatedTag {
+  pub tag: TagName,
+  pub message: Message,
+  pub object: Sha,
+  pub object_type: GitObjectType,
+  pub tagger: Option<CommitIdentity>,
+}
+
+// Custom encode for the reserved word "type"
+impl Encodable for CreateAnnotatedTag {
+  fn encode<S: Encoder>(&self, s: &mut S) -> Result<(), S::Error> {
+    s.emit_struct("CreateAnnotatedTag", 5, |s| {
+      try!(s.emit_struct_field("tag", 0, |s| self.tag.encode(s)));
+      try!(s.emit_struct_field("message", 1, |s| self.message.encode(s)));
+      try!(s.emit_struct_field("object", 2, |s| self.object.encode(s)));
+      try!(s.emit_struct_field("type", 3, |s| self.object_type.encode(s)));
+      s.emit_struct_field("tagger", 4, |s| self.tagger.encode(s))
+    })
+  }
+}
+
+/// A single path change within a multi-file commit. Writing a path both
+/// adds new files and replaces existing ones.
+#[derive(Debug, PartialEq, Clone)]
+pub enum FileChange {
+  Write { path: Filename, content: Vec<u8>, mode: FileMode },
+  Delete { path: Filename },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct MultiFileCommit {
+  pub branch: BranchName,
+  pub message: Message,
+  pub changes: Vec<FileChange>,
+  pub author: Option<CommitIdentity>,
+  pub committer: Option<CommitIdentity>
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+  use rustc_serialize::json;
+
+  use super::{
+    CreateTree,
+    CreateTreeEntry,
+    CreateGitCommit,
+    FileMode,
+    GitObjectType,
+  };
+
+  #[test]
+  fn it_leaves_an_unset_base_tree_out_of_the_body() {
+    let entry = CreateTreeEntry { path: "gone.txt".to_owned(), mode: FileMode::File, object_type: GitObjectType::Blob, sha: None, content: None };
+    let tree = CreateTree { base_tree: None, tree: vec![entry] };
+    expect!(json::encode(&tree).unwrap()).to(be_equal_to(r#"{"tree":[{"path":"gone.txt","mode":"100644","type":"blob","sha":null}]}"#.to_owned()));
+  }
+
+  #[test]
+  fn it_leaves_an_unset_author_and_committer_out_of_the_body() {
+    let commit = CreateGitCommit { message: "Update".to_owned(), tree: "tree1".to_owned(), parents: vec!["head1".to_owned()], author: None, committer: None };
+    expect!(json::encode(&commit).unwrap()).to(be_equal_to(r#"{"message":"Update","tree":"tree1","parents":["head1"]}"#.to_owned()));
+  }
+}
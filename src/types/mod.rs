@@ -48,10 +48,64 @@ macro_rules! custom_enum_decode_encode {
   }
 }
 
+// Encodes a struct of Option fields, leaving out the unset ones so that
+// Github keeps their current values instead of clearing them. Fields in the
+// optional braces are always sent, under the given name.
+#[macro_export]
+macro_rules! optional_fields_encode {
+  (
+    $struct_ty:ident [ $( $field:ident, )* ]
+  ) => {
+    optional_fields_encode!($struct_ty {} [ $( $field, )* ]);
+  };
+  (
+    $struct_ty:ident { $( $key:expr => $required:ident, )* } [ $( $field:ident, )* ]
+  ) => {
+    impl Encodable for $struct_ty {
+      #[allow(unused_assignments, unused_mut)]
+      fn encode<S: Encoder>(&self, s: &mut S) -> Result<(), S::Error> {
+        let set_fields = 0 $( + { let _ = $key; 1 } )* $( + self.$field.iter().count() )*;
+        s.emit_struct(stringify!($struct_ty), set_fields, |s| {
+          // The json encoder puts a comma before every field but index 0,
+          // so only the emitted fields are counted
+          let mut idx = 0;
+          $(
+            try!(s.emit_struct_field($key, idx, |s| self.$required.encode(s)));
+            idx += 1;
+          )*
+          $(
+            if let Some(ref value) = self.$field {
+              try!(s.emit_struct_field(stringify!($field), idx, |s| value.encode(s)));
+              idx += 1;
+            }
+          )*
+          Ok(())
+        })
+      }
+    }
+  }
+}
+
+pub mod audit;
+pub mod branch_protection;
+pub mod checks;
 pub mod comments;
 pub mod commits;
+pub mod contents;
+pub mod deploy_keys;
+pub mod deployments;
+pub mod git_data;
+pub mod notifications;
+pub mod orgs;
 pub mod pull_requests;
+pub mod reactions;
+pub mod releases;
+pub mod search;
 pub mod repos;
+pub mod stats;
+pub mod statuses;
+pub mod teams;
+pub mod user_keys;
 pub mod users;
 
 use time::Tm;
@@ -66,7 +120,17 @@ use rustc_serialize::{
   Encoder,
 };
 
-use types::repos::LegacyRepo;
+use types::repos::{
+  LegacyRepo,
+  ArchiveFormat,
+  Permission,
+};
+
+use types::teams::{
+  TeamId,
+  TeamSlug,
+  TeamPrivacy,
+};
 
 use types::commits::{
   PushCommit,
@@ -80,9 +144,11 @@ use types::users::{
   UserName
 };
 
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::error::Error;
 use std::fmt;
+use std::time::Duration;
 
 pub type Body = String;
 pub type HeadQuery = String;
@@ -92,6 +158,7 @@ pub type Message = String;
 pub type Sha = String;
 pub type Url = String;
 pub type Filename = String;
+pub type GitRef = String;
 pub type OrganizationName = String;
 
 #[derive(Debug, PartialEq, Clone)]
@@ -99,7 +166,10 @@ pub enum GitErr {
   NotImplemented(String),
   EncodeErr(String),
   DecodeErr(String),
-  NetworkErr(String)
+  NetworkErr(String),
+  UnexpectedStatus(String),
+  TimedOut(String),
+  RetriesExhausted(String)
 }
 
 impl fmt::Display for GitErr {
@@ -108,7 +178,10 @@ impl fmt::Display for GitErr {
       GitErr::NotImplemented(ref err) => write!(f, "Not Implemented error: {}", err),
       GitErr::EncodeErr(ref err) => write!(f, "Encode error: {}", err),
       GitErr::DecodeErr(ref err) => write!(f, "Decode error: {}", err),
-      GitErr::NetworkErr(ref err) => write!(f, "Network error: {}", err)
+      GitErr::NetworkErr(ref err) => write!(f, "Network error: {}", err),
+      GitErr::UnexpectedStatus(ref err) => write!(f, "Unexpected status: {}", err),
+      GitErr::TimedOut(ref err) => write!(f, "Timed out: {}", err),
+      GitErr::RetriesExhausted(ref err) => write!(f, "Retries exhausted: {}", err)
     }
   }
 }
@@ -119,11 +192,56 @@ impl Error for GitErr {
       GitErr::NotImplemented(ref err) => err,
       GitErr::EncodeErr(ref err) => err,
       GitErr::DecodeErr(ref err) => err,
-      GitErr::NetworkErr(ref err) => err
+      GitErr::NetworkErr(ref err) => err,
+      GitErr::UnexpectedStatus(ref err) => err,
+      GitErr::TimedOut(ref err) => err,
+      GitErr::RetriesExhausted(ref err) => err
     }
   }
 }
 
+// Encoded as its message, so that reports carrying errors can be serialized
+impl Encodable for GitErr {
+  fn encode<S: Encoder>(&self, s: &mut S) -> Result<(), S::Error> {
+    s.emit_str(&self.to_string())
+  }
+}
+
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct PageQuery {
+  pub per_page: Option<u32>,
+  pub page: Option<u32>
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct PollOptions {
+  pub timeout: Duration,
+  pub initial_interval: Duration,
+  pub max_interval: Duration
+}
+
+/// A response to a conditional request. `content` is None when nothing
+/// changed since `last_modified` was sent; `poll_interval` is how long
+/// Github asks clients to wait before polling again.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PollResponse<D> {
+  pub content: Option<D>,
+  pub poll_interval: Option<Duration>,
+  pub last_modified: Option<String>
+}
+
+/// A response to a rate limited request. `content` is None when Github
+/// refused the request for exceeding the limit; the rest is read from the
+/// rate limit headers.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RateLimitedResponse<D> {
+  pub content: Option<D>,
+  pub remaining: Option<u32>,
+  /// Seconds since the unix epoch
+  pub reset: Option<i64>,
+  pub retry_after: Option<Duration>
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct GitTm(Tm);
 
@@ -247,19 +365,89 @@ pub struct ContributorsQuery {
   pub anon: bool
 }
 
-#[derive(RustcDecodable, Debug, Clone)]
-pub struct LanguagePile(HashMap<String, u32>); // TODO: Types
+pub type Language = String;
+
+/// Bytes of code per language
+#[derive(Debug, PartialEq, Clone)]
+pub struct LanguagePile(HashMap<Language, u64>);
+
+// Custom decode, as Github sends a plain object rather than a tuple
+impl Decodable for LanguagePile {
+  fn decode<D: Decoder>(d: &mut D) -> Result<LanguagePile, D::Error> {
+    Decodable::decode(d).map(LanguagePile)
+  }
+}
+
+fn share(bytes: u64, total: u64) -> f64 {
+  match total {
+    0 => 0.0,
+    total => bytes as f64 * 100.0 / total as f64
+  }
+}
+
+impl LanguagePile {
+  pub fn new() -> LanguagePile {
+    LanguagePile(HashMap::new())
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+
+  pub fn bytes(&self, language: &str) -> u64 {
+    self.0.get(language).cloned().unwrap_or(0)
+  }
+
+  pub fn total_bytes(&self) -> u64 {
+    self.0.values().fold(0, |total, bytes| total + bytes)
+  }
+
+  /// Share of the total bytes, from 0.0 to 100.0
+  pub fn percentage(&self, language: &str) -> f64 {
+    share(self.bytes(language), self.total_bytes())
+  }
+
+  /// Languages with the most bytes first, ties ordered by name
+  pub fn sorted(&self) -> Vec<(Language, u64)> {
+    let mut languages: Vec<(Language, u64)> =
+      self.0.iter().map(|(language, bytes)| (language.clone(), *bytes)).collect();
+    languages.sort_by(|a, b| match b.1.cmp(&a.1) {
+      Ordering::Equal => a.0.cmp(&b.0),
+      ordering => ordering
+    });
+    languages
+  }
+
+  pub fn percentages(&self) -> Vec<(Language, f64)> {
+    let total = self.total_bytes();
+    self
+      .sorted()
+      .into_iter()
+      .map(|(language, bytes)| (language, share(bytes, total)))
+      .collect()
+  }
+
+  pub fn primary(&self) -> Option<Language> {
+    self.sorted().into_iter().next().map(|(language, _)| language)
+  }
+
+  pub fn add(&mut self, other: &LanguagePile) {
+    for (language, bytes) in other.0.iter() {
+      *self.0.entry(language.clone()).or_insert(0) += *bytes;
+    }
+  }
+}
 
-// TODO: Types
 #[derive(RustcDecodable, Debug, Clone)]
 pub struct Team {
-  pub id: u32,
+  pub id: TeamId,
   pub url: Url,
+  pub html_url: Option<Url>,
   pub name: String,
-  pub slug: String,
-  pub description: String,
-  pub privacy: String,
-  pub permission: String,
+  pub slug: TeamSlug,
+  pub description: Option<String>,
+  pub privacy: Option<TeamPrivacy>,
+  pub permission: Permission,
   pub members_url: Url,
   pub repositories_url: Url,
 }
@@ -273,6 +461,15 @@ pub struct Tag {
   pub tarball_url: Url
 }
 
+impl Tag {
+  pub fn archive_url(&self, format: &ArchiveFormat) -> &Url {
+    match *format {
+      ArchiveFormat::Tarball => &self.tarball_url,
+      ArchiveFormat::Zipball => &self.zipball_url
+    }
+  }
+}
+
 #[derive(RustcDecodable, Debug, Clone)]
 pub struct Branch {
   pub name: BranchName,
@@ -282,13 +479,62 @@ pub struct Branch {
 #[derive(RustcDecodable, Debug, Clone)]
 pub struct FullBranch {
   pub name: BranchName,
-  pub commit: GithubCommit
+  pub commit: GithubCommit,
+  pub protected: Option<bool>
   // TODO: _links
 }
 
-#[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum DeletedStatus {
   Deleted,
   NotDeleted
 }
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+
+  use super::LanguagePile;
+
+  fn pile(languages: &[(&str, u64)]) -> LanguagePile {
+    let bytes: HashMap<String, u64> = languages.iter().map(|&(language, bytes)| (language.to_owned(), bytes)).collect();
+    LanguagePile(bytes)
+  }
+
+  #[test]
+  fn it_handles_an_empty_pile() {
+    let empty = LanguagePile::new();
+    expect!(empty.total_bytes()).to(be_equal_to(0));
+    expect!(empty.percentage("Rust")).to(be_equal_to(0.0));
+    expect!(empty.percentages()).to(be_equal_to(vec![]));
+    expect!(empty.primary()).to(be_equal_to(None));
+  }
+
+  #[test]
+  fn it_orders_ties_by_name() {
+    let languages = pile(&[("Shell", 10), ("C", 10), ("Rust", 30)]);
+    let expected = vec![("Rust".to_owned(), 30), ("C".to_owned(), 10), ("Shell".to_owned(), 10)];
+    expect!(languages.sorted()).to(be_equal_to(expected));
+    expect!(languages.primary()).to(be_equal_to(Some("Rust".to_owned())));
+  }
+
+  #[test]
+  fn it_computes_percentages_summing_to_one_hundred() {
+    let languages = pile(&[("Rust", 700), ("C", 200), ("Shell", 100), ("Makefile", 3)]);
+    let percentages = languages.percentages();
+    let sum = percentages.iter().fold(0.0, |sum, &(_, percentage)| sum + percentage);
+    expect!((sum - 100.0).abs() < 1e-9).to(be_equal_to(true));
+    expect!(percentages[0].0.clone()).to(be_equal_to("Rust".to_owned()));
+    expect!(languages.percentage("Rust")).to(be_equal_to(percentages[0].1));
+  }
+
+  #[test]
+  fn it_adds_piles() {
+    let mut languages = pile(&[("Rust", 10)]);
+    languages.add(&pile(&[("Rust", 5), ("C", 1)]));
+    expect!(languages).to(be_equal_to(pile(&[("Rust", 15), ("C", 1)])));
+  }
+}
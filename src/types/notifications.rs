@@ -0,0 +1,163 @@
+use rustc_serialize::{
+  Decodable,
+  Decoder,
+  Encodable,
+  Encoder,
+};
+
+use types::{
+  Url,
+  GitTm,
+};
+
+use types::repos::RepoSummary;
+
+pub type ThreadId = String;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum NotificationReason {
+  ApprovalRequested,
+  Assign,
+  Author,
+  Comment,
+  CiActivity,
+  Invitation,
+  Manual,
+  MemberFeatureRequested,
+  Mention,
+  ReviewRequested,
+  SecurityAlert,
+  SecurityAdvisoryCredit,
+  StateChange,
+  Subscribed,
+  TeamMention,
+}
+
+custom_enum_decode_encode!(
+  NotificationReason [
+    "approval_requested" <=> [NotificationReason::ApprovalRequested],
+    "assign" <=> [NotificationReason::Assign],
+    "author" <=> [NotificationReason::Author],
+    "comment" <=> [NotificationReason::Comment],
+    "ci_activity" <=> [NotificationReason::CiActivity],
+    "invitation" <=> [NotificationReason::Invitation],
+    "manual" <=> [NotificationReason::Manual],
+    "member_feature_requested" <=> [NotificationReason::MemberFeatureRequested],
+    "mention" <=> [NotificationReason::Mention],
+    "review_requested" <=> [NotificationReason::ReviewRequested],
+    "security_alert" <=> [NotificationReason::SecurityAlert],
+    "security_advisory_credit" <=> [NotificationReason::SecurityAdvisoryCredit],
+    "state_change" <=> [NotificationReason::StateChange],
+    "subscribed" <=> [NotificationReason::Subscribed],
+    "team_mention" <=> [NotificationReason::TeamMention],
+  ]
+);
+
+#[derive(Debug, Clone)]
+pub struct NotificationSubject {
+  pub title: String,
+  pub url: Option<Url>,
+  pub latest_comment_url: Option<Url>,
+  pub subject_type: String
+}
+
+// Custom decode for the reserved word "type"
+impl Decodable for NotificationSubject {
+  fn decode<D: Decoder>(d: &mut D) -> Result<NotificationSubject, D::Error> {
+    d.read_struct("NotificationSubject", 4, |d| {
+      Ok(NotificationSubject {
+        title: try!(d.read_struct_field("title", 0, Decodable::decode)),
+        url: try!(d.read_struct_field("url", 1, Decodable::decode)),
+        latest_comment_url: try!(d.read_struct_field("latest_comment_url", 2, Decodable::decode)),
+        subject_type: try!(d.read_struct_field("type", 3, Decodable::decode)),
+      })
+    })
+  }
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct Notification {
+  pub id: ThreadId,
+  pub repository: RepoSummary,
+  pub subject: NotificationSubject,
+  pub reason: NotificationReason,
+  pub unread: bool,
+  pub updated_at: GitTm,
+  pub last_read_at: Option<GitTm>,
+  pub url: Url,
+  pub subscription_url: Url
+}
+
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct NotificationsQuery {
+  pub all: Option<bool>,
+  pub participating: Option<bool>,
+  pub since: Option<GitTm>,
+  pub before: Option<GitTm>,
+  pub per_page: Option<u32>,
+  pub page: Option<u32>
+}
+
+/// Without `last_read_at` everything up to now is marked as read
+#[derive(Debug, PartialEq, Clone)]
+pub struct MarkAsRead {
+  pub last_read_at: Option<GitTm>
+}
+
+optional_fields_encode!(
+  MarkAsRead [
+    last_read_at,
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ReadStatus {
+  Read,
+  /// Github is marking the notifications as read in the background
+  ReadPending
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct ThreadSubscription {
+  pub subscribed: bool,
+  pub ignored: bool,
+  pub reason: Option<String>,
+  pub created_at: Option<GitTm>,
+  pub url: Url,
+  pub thread_url: Option<Url>
+}
+
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct SetThreadSubscription {
+  pub ignored: bool
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+  use rustc_serialize::json;
+
+  use super::{
+    MarkAsRead,
+    NotificationSubject,
+  };
+
+  #[test]
+  fn it_decodes_the_subject_type() {
+    let subject: NotificationSubject = json::decode(r#"{
+      "title": "Greetings",
+      "url": "https://api.github.com/repos/octokit/octokit.rb/issues/123",
+      "latest_comment_url": null,
+      "type": "Issue"
+    }"#).unwrap();
+    expect!(subject.subject_type).to(be_equal_to("Issue".to_owned()));
+    expect!(subject.latest_comment_url).to(be_equal_to(None));
+  }
+
+  #[test]
+  fn it_marks_everything_read_without_a_last_read_at() {
+    let details = MarkAsRead { last_read_at: None };
+    expect!(json::encode(&details).unwrap()).to(be_equal_to("{}".to_owned()));
+  }
+}
@@ -0,0 +1,298 @@
+use rustc_serialize::{
+  Decodable,
+  Decoder,
+  Encodable,
+  Encoder,
+};
+
+use types::{
+  Url,
+  GitTm,
+  OrganizationName,
+};
+
+use types::repos::PermissionLevel;
+use types::users::{
+  GithubUser,
+  UserId,
+  Plan,
+};
+
+pub type OrgId = u32;
+pub type OrgInvitationId = u64;
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct OrgSummary {
+  pub login: OrganizationName,
+  pub id: OrgId,
+  pub url: Url,
+  pub repos_url: Url,
+  pub events_url: Url,
+  pub hooks_url: Url,
+  pub issues_url: Url,
+  pub members_url: Url,
+  pub public_members_url: Url,
+  pub avatar_url: Url,
+  pub description: Option<String>
+}
+
+/// Private fields are only present for members of the organization
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct FullOrganization {
+  pub login: OrganizationName,
+  pub id: OrgId,
+  pub url: Url,
+  pub html_url: Url,
+  pub repos_url: Url,
+  pub members_url: Url,
+  pub public_members_url: Url,
+  pub avatar_url: Url,
+  pub description: Option<String>,
+  pub name: Option<String>,
+  pub company: Option<String>,
+  pub blog: Option<Url>,
+  pub location: Option<String>,
+  pub email: Option<String>,
+  pub twitter_username: Option<String>,
+  pub is_verified: Option<bool>,
+  pub has_organization_projects: bool,
+  pub has_repository_projects: bool,
+  pub public_repos: u32,
+  pub public_gists: u32,
+  pub followers: u32,
+  pub following: u32,
+  pub created_at: GitTm,
+  pub updated_at: GitTm,
+  pub total_private_repos: Option<u32>,
+  pub owned_private_repos: Option<u32>,
+  pub private_gists: Option<u32>,
+  pub disk_usage: Option<u64>,
+  pub collaborators: Option<u32>,
+  pub billing_email: Option<String>,
+  pub plan: Option<Plan>,
+  pub default_repository_permission: Option<PermissionLevel>,
+  pub members_can_create_repositories: Option<bool>,
+  pub two_factor_requirement_enabled: Option<bool>
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct EditOrganization {
+  pub billing_email: Option<String>,
+  pub company: Option<String>,
+  pub email: Option<String>,
+  pub twitter_username: Option<String>,
+  pub location: Option<String>,
+  pub name: Option<String>,
+  pub description: Option<String>,
+  pub blog: Option<Url>,
+  pub has_organization_projects: Option<bool>,
+  pub has_repository_projects: Option<bool>,
+  pub default_repository_permission: Option<PermissionLevel>,
+  pub members_can_create_repositories: Option<bool>
+}
+
+optional_fields_encode!(
+  EditOrganization [
+    billing_email,
+    company,
+    email,
+    twitter_username,
+    location,
+    name,
+    description,
+    blog,
+    has_organization_projects,
+    has_repository_projects,
+    default_repository_permission,
+    members_can_create_repositories,
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum MemberFilter {
+  TwoFactorDisabled,
+  All,
+}
+
+custom_enum_decode_encode!(
+  MemberFilter [
+    "2fa_disabled" <=> [MemberFilter::TwoFactorDisabled],
+    "all" <=> [MemberFilter::All],
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum MemberRoleFilter {
+  All,
+  Admin,
+  Member,
+}
+
+custom_enum_decode_encode!(
+  MemberRoleFilter [
+    "all" <=> [MemberRoleFilter::All],
+    "admin" <=> [MemberRoleFilter::Admin],
+    "member" <=> [MemberRoleFilter::Member],
+  ]
+);
+
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct MembersQuery {
+  pub filter: Option<MemberFilter>,
+  pub role: Option<MemberRoleFilter>,
+  pub per_page: Option<u32>,
+  pub page: Option<u32>
+}
+
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct OutsideCollaboratorsQuery {
+  pub filter: Option<MemberFilter>,
+  pub per_page: Option<u32>,
+  pub page: Option<u32>
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum OrgRole {
+  Admin,
+  Member,
+  BillingManager,
+}
+
+custom_enum_decode_encode!(
+  OrgRole [
+    "admin" <=> [OrgRole::Admin],
+    "member" <=> [OrgRole::Member],
+    "billing_manager" <=> [OrgRole::BillingManager],
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum MembershipState {
+  Active,
+  Pending,
+}
+
+custom_enum_decode_encode!(
+  MembershipState [
+    "active" <=> [MembershipState::Active],
+    "pending" <=> [MembershipState::Pending],
+  ]
+);
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct OrgMembership {
+  pub url: Url,
+  pub state: MembershipState,
+  pub role: OrgRole,
+  pub organization_url: Url,
+  pub organization: OrgSummary,
+  pub user: Option<GithubUser>
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct SetOrgMembership {
+  pub role: Option<OrgRole>
+}
+
+optional_fields_encode!(
+  SetOrgMembership [
+    role,
+  ]
+);
+
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct EditOwnOrgMembership {
+  pub state: MembershipState
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum MembershipStatus {
+  Member,
+  NotMember
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ConvertedStatus {
+  Converted,
+  NotConverted
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum InvitationRole {
+  Admin,
+  DirectMember,
+  BillingManager,
+  HiringManager,
+  Reinstate,
+}
+
+custom_enum_decode_encode!(
+  InvitationRole [
+    "admin" <=> [InvitationRole::Admin],
+    "direct_member" <=> [InvitationRole::DirectMember],
+    "billing_manager" <=> [InvitationRole::BillingManager],
+    "hiring_manager" <=> [InvitationRole::HiringManager],
+    "reinstate" <=> [InvitationRole::Reinstate],
+  ]
+);
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct OrgInvitation {
+  pub id: OrgInvitationId,
+  pub login: Option<String>,
+  pub email: Option<String>,
+  pub role: InvitationRole,
+  pub created_at: GitTm,
+  pub inviter: GithubUser,
+  pub team_count: u32,
+  pub invitation_teams_url: Url
+}
+
+/// Either `invitee_id` or `email` identifies who is invited
+#[derive(Debug, PartialEq, Clone)]
+pub struct CreateOrgInvitation {
+  pub invitee_id: Option<UserId>,
+  pub email: Option<String>,
+  pub role: Option<InvitationRole>,
+  pub team_ids: Option<Vec<u32>>
+}
+
+optional_fields_encode!(
+  CreateOrgInvitation [
+    invitee_id,
+    email,
+    role,
+    team_ids,
+  ]
+);
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+  use rustc_serialize::json;
+
+  use super::{
+    CreateOrgInvitation,
+    InvitationRole,
+    SetOrgMembership,
+  };
+
+  #[test]
+  fn it_invites_by_email_alone() {
+    let invitation = CreateOrgInvitation { invitee_id: None, email: Some("octocat@github.com".to_owned()), role: None, team_ids: None };
+    expect!(json::encode(&invitation).unwrap()).to(be_equal_to(r#"{"email":"octocat@github.com"}"#.to_owned()));
+  }
+
+  #[test]
+  fn it_invites_by_id_alone() {
+    let invitation = CreateOrgInvitation { invitee_id: Some(1), email: None, role: Some(InvitationRole::DirectMember), team_ids: None };
+    expect!(json::encode(&invitation).unwrap()).to(be_equal_to(r#"{"invitee_id":1,"role":"direct_member"}"#.to_owned()));
+  }
+
+  #[test]
+  fn it_sends_an_empty_membership_without_a_role() {
+    let membership = SetOrgMembership { role: None };
+    expect!(json::encode(&membership).unwrap()).to(be_equal_to("{}".to_owned()));
+  }
+}
@@ -0,0 +1,122 @@
+use rustc_serialize::{
+  Decodable,
+  Decoder,
+  Encodable,
+  Encoder,
+};
+
+use types::{
+  Url,
+  GitTm,
+};
+
+use types::users::GithubUser;
+
+pub type ReactionId = u32;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ReactionContent {
+  PlusOne,
+  MinusOne,
+  Laugh,
+  Confused,
+  Heart,
+  Hooray,
+  Rocket,
+  Eyes,
+}
+
+custom_enum_decode_encode!(
+  ReactionContent [
+    "+1" <=> [ReactionContent::PlusOne],
+    "-1" <=> [ReactionContent::MinusOne],
+    "laugh" <=> [ReactionContent::Laugh],
+    "confused" <=> [ReactionContent::Confused],
+    "heart" <=> [ReactionContent::Heart],
+    "hooray" <=> [ReactionContent::Hooray],
+    "rocket" <=> [ReactionContent::Rocket],
+    "eyes" <=> [ReactionContent::Eyes],
+  ]
+);
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct Reaction {
+  pub id: ReactionId,
+  pub user: GithubUser,
+  pub content: ReactionContent,
+  pub created_at: GitTm
+}
+
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct CreateReaction {
+  pub content: ReactionContent
+}
+
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct ReactionQuery {
+  pub content: Option<ReactionContent>
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ReactionRollup {
+  pub url: Url,
+  pub total_count: u32,
+  pub plus_one: u32,
+  pub minus_one: u32,
+  pub laugh: u32,
+  pub confused: u32,
+  pub heart: u32,
+  pub hooray: u32,
+  pub rocket: u32,
+  pub eyes: u32,
+}
+
+// Custom decode for the "+1" and "-1" keys
+impl Decodable for ReactionRollup {
+  fn decode<D: Decoder>(d: &mut D) -> Result<ReactionRollup, D::Error> {
+    d.read_struct("ReactionRollup", 10, |d| {
+      Ok(ReactionRollup {
+        url: try!(d.read_struct_field("url", 0, Decodable::decode)),
+        total_count: try!(d.read_struct_field("total_count", 1, Decodable::decode)),
+        plus_one: try!(d.read_struct_field("+1", 2, Decodable::decode)),
+        minus_one: try!(d.read_struct_field("-1", 3, Decodable::decode)),
+        laugh: try!(d.read_struct_field("laugh", 4, Decodable::decode)),
+        confused: try!(d.read_struct_field("confused", 5, Decodable::decode)),
+        heart: try!(d.read_struct_field("heart", 6, Decodable::decode)),
+        hooray: try!(d.read_struct_field("hooray", 7, Decodable::decode)),
+        rocket: try!(d.read_struct_field("rocket", 8, Decodable::decode)),
+        eyes: try!(d.read_struct_field("eyes", 9, Decodable::decode)),
+      })
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+  use rustc_serialize::json;
+
+  use super::ReactionRollup;
+
+  #[test]
+  fn it_decodes_plus_and_minus_one_keys() {
+    let encoded = r#"{
+      "url": "https://api.github.com/repos/test_owner/test_repo/issues/1/reactions",
+      "total_count": 6,
+      "+1": 3,
+      "-1": 1,
+      "laugh": 0,
+      "confused": 0,
+      "heart": 2,
+      "hooray": 0,
+      "rocket": 0,
+      "eyes": 0
+    }"#;
+    let rollup: ReactionRollup = json::decode(encoded).unwrap();
+    expect!(rollup.total_count).to(be_equal_to(6));
+    expect!(rollup.plus_one).to(be_equal_to(3));
+    expect!(rollup.minus_one).to(be_equal_to(1));
+    expect!(rollup.heart).to(be_equal_to(2));
+  }
+}
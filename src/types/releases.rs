@@ -0,0 +1,195 @@
+use rustc_serialize::{
+  Decodable,
+  Decoder,
+  Encodable,
+  Encoder,
+};
+
+use types::{
+  Url,
+  GitTm,
+  GitRef,
+  Message,
+  Filename,
+};
+
+use types::git_data::TagName;
+use types::users::GithubUser;
+
+pub type ReleaseId = u64;
+pub type AssetId = u64;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum AssetState {
+  Uploaded,
+  Open,
+}
+
+custom_enum_decode_encode!(
+  AssetState [
+    "uploaded" <=> [AssetState::Uploaded],
+    "open" <=> [AssetState::Open],
+  ]
+);
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct ReleaseAsset {
+  pub id: AssetId,
+  pub url: Url,
+  pub browser_download_url: Url,
+  pub name: Filename,
+  pub label: Option<String>,
+  pub state: AssetState,
+  pub content_type: String,
+  pub size: u64,
+  pub download_count: u32,
+  pub created_at: GitTm,
+  pub updated_at: GitTm,
+  pub uploader: Option<GithubUser>
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct Release {
+  pub id: ReleaseId,
+  pub url: Url,
+  pub html_url: Url,
+  pub assets_url: Url,
+  pub upload_url: Url,
+  pub tarball_url: Option<Url>,
+  pub zipball_url: Option<Url>,
+  pub tag_name: TagName,
+  pub target_commitish: GitRef,
+  pub name: Option<String>,
+  pub body: Option<Message>,
+  pub draft: bool,
+  pub prerelease: bool,
+  pub created_at: GitTm,
+  pub published_at: Option<GitTm>,
+  pub author: GithubUser,
+  pub assets: Vec<ReleaseAsset>
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CreateRelease {
+  pub tag_name: TagName,
+  pub target_commitish: Option<GitRef>,
+  pub name: Option<String>,
+  pub body: Option<Message>,
+  pub draft: Option<bool>,
+  pub prerelease: Option<bool>,
+  pub generate_release_notes: Option<bool>
+}
+
+optional_fields_encode!(
+  CreateRelease {
+    "tag_name" => tag_name,
+  } [
+    target_commitish,
+    name,
+    body,
+    draft,
+    prerelease,
+    generate_release_notes,
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct EditRelease {
+  pub tag_name: Option<TagName>,
+  pub target_commitish: Option<GitRef>,
+  pub name: Option<String>,
+  pub body: Option<Message>,
+  pub draft: Option<bool>,
+  pub prerelease: Option<bool>
+}
+
+optional_fields_encode!(
+  EditRelease [
+    tag_name,
+    target_commitish,
+    name,
+    body,
+    draft,
+    prerelease,
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct GenerateReleaseNotes {
+  pub tag_name: TagName,
+  pub target_commitish: Option<GitRef>,
+  pub previous_tag_name: Option<TagName>
+}
+
+optional_fields_encode!(
+  GenerateReleaseNotes {
+    "tag_name" => tag_name,
+  } [
+    target_commitish,
+    previous_tag_name,
+  ]
+);
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct ReleaseNotes {
+  pub name: String,
+  pub body: Message
+}
+
+/// Sent as url parameters alongside the raw asset body.
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct UploadAsset {
+  pub name: Filename,
+  pub label: Option<String>
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct EditAsset {
+  pub name: Option<Filename>,
+  pub label: Option<String>
+}
+
+optional_fields_encode!(
+  EditAsset [
+    name,
+    label,
+  ]
+);
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+  use rustc_serialize::json;
+
+  use super::{
+    CreateRelease,
+    EditAsset,
+    EditRelease,
+    GenerateReleaseNotes,
+  };
+
+  #[test]
+  fn it_leaves_unset_fields_out_of_a_new_release() {
+    let create = CreateRelease { tag_name: "v1.0.0".to_owned(), target_commitish: None, name: None, body: None, draft: None, prerelease: None, generate_release_notes: None };
+    expect!(json::encode(&create).unwrap()).to(be_equal_to(r#"{"tag_name":"v1.0.0"}"#.to_owned()));
+  }
+
+  #[test]
+  fn it_leaves_unset_fields_out_of_release_notes_generation() {
+    let notes = GenerateReleaseNotes { tag_name: "v1.0.0".to_owned(), target_commitish: None, previous_tag_name: Some("v0.9.0".to_owned()) };
+    expect!(json::encode(&notes).unwrap()).to(be_equal_to(r#"{"tag_name":"v1.0.0","previous_tag_name":"v0.9.0"}"#.to_owned()));
+  }
+
+  #[test]
+  fn it_leaves_unset_fields_out_of_a_release_edit() {
+    let edit = EditRelease { tag_name: None, target_commitish: None, name: None, body: None, draft: Some(false), prerelease: None };
+    expect!(json::encode(&edit).unwrap()).to(be_equal_to(r#"{"draft":false}"#.to_owned()));
+  }
+
+  #[test]
+  fn it_leaves_unset_fields_out_of_an_asset_edit() {
+    let edit = EditAsset { name: None, label: Some("Linux build".to_owned()) };
+    expect!(json::encode(&edit).unwrap()).to(be_equal_to(r#"{"label":"Linux build"}"#.to_owned()));
+  }
+}
@@ -8,6 +8,7 @@ use rustc_serialize::{
 use types::{
   Url,
   BranchName,
+  OrganizationName,
   SortDirection,
   GitTm,
   Message,
@@ -22,7 +23,7 @@ use types::users::{
 
 pub type RepoName = String;
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
 pub struct Repository {
   pub owner: UserName,
   pub repo_name: RepoName
@@ -31,7 +32,9 @@ pub struct Repository {
 #[derive(RustcDecodable, Debug, Clone)]
 pub struct RepoPermissions {
   pub admin: bool,
+  pub maintain: Option<bool>,
   pub push: bool,
+  pub triage: Option<bool>,
   pub pull: bool
 }
 
@@ -41,7 +44,7 @@ pub struct Repo {
   pub owner: GithubUser,
   pub name: RepoName,
   pub full_name: RepoName,
-  pub description: String,
+  pub description: Option<String>,
   pub private: bool,
   pub fork: bool,
   pub url: Url,
@@ -98,6 +101,7 @@ pub struct Repo {
   pub has_wiki: bool,
   pub has_pages: bool,
   pub has_downloads: bool,
+  pub archived: Option<bool>,
   pub pushed_at: GitTm,
   pub created_at: GitTm,
   pub updated_at: GitTm,
@@ -237,7 +241,7 @@ pub struct CreateRepository {
   pub license_template: Option<String>
 }
 
-#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct EditRepository {
   pub name: RepoName,
   pub description: Option<Message>,
@@ -246,10 +250,226 @@ pub struct EditRepository {
   pub has_issues: Option<bool>,
   pub has_wiki: Option<bool>,
   pub has_downloads: Option<bool>,
-  pub default_branch: Option<BranchName>
+  pub default_branch: Option<BranchName>,
+  pub archived: Option<bool>
+}
+
+optional_fields_encode!(
+  EditRepository {
+    "name" => name,
+  } [
+    description,
+    homepage,
+    private,
+    has_issues,
+    has_wiki,
+    has_downloads,
+    default_branch,
+    archived,
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct TransferRepository {
+  pub new_owner: UserName,
+  pub new_name: Option<RepoName>,
+  pub team_ids: Option<Vec<u32>>
 }
 
+optional_fields_encode!(
+  TransferRepository {
+    "new_owner" => new_owner,
+  } [
+    new_name,
+    team_ids,
+  ]
+);
+
 #[derive(RustcEncodable, Debug, PartialEq, Clone)]
 pub struct PublicReposQuery {
   pub since: String
 }
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ForkSortables {
+  Newest,
+  Oldest,
+  Stargazers,
+  Watchers,
+}
+
+custom_enum_decode_encode!(
+  ForkSortables [
+    "newest" <=> [ForkSortables::Newest],
+    "oldest" <=> [ForkSortables::Oldest],
+    "stargazers" <=> [ForkSortables::Stargazers],
+    "watchers" <=> [ForkSortables::Watchers],
+  ]
+);
+
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct ForksQuery {
+  pub sort: Option<ForkSortables>
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CreateFork {
+  pub organization: Option<OrganizationName>,
+  pub name: Option<RepoName>,
+  pub default_branch_only: Option<bool>
+}
+
+optional_fields_encode!(
+  CreateFork [
+    organization,
+    name,
+    default_branch_only,
+  ]
+);
+
+pub type InvitationId = u32;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Permission {
+  Pull,
+  Triage,
+  Push,
+  Maintain,
+  Admin,
+}
+
+custom_enum_decode_encode!(
+  Permission [
+    "pull" <=> [Permission::Pull],
+    "triage" <=> [Permission::Triage],
+    "push" <=> [Permission::Push],
+    "maintain" <=> [Permission::Maintain],
+    "admin" <=> [Permission::Admin],
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum PermissionLevel {
+  Admin,
+  Maintain,
+  Write,
+  Triage,
+  Read,
+  NoPermission,
+}
+
+custom_enum_decode_encode!(
+  PermissionLevel [
+    "admin" <=> [PermissionLevel::Admin],
+    "maintain" <=> [PermissionLevel::Maintain],
+    "write" <=> [PermissionLevel::Write],
+    "triage" <=> [PermissionLevel::Triage],
+    "read" <=> [PermissionLevel::Read],
+    "none" <=> [PermissionLevel::NoPermission],
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum CollaboratorAffiliation {
+  Outside,
+  Direct,
+  All,
+}
+
+custom_enum_decode_encode!(
+  CollaboratorAffiliation [
+    "outside" <=> [CollaboratorAffiliation::Outside],
+    "direct" <=> [CollaboratorAffiliation::Direct],
+    "all" <=> [CollaboratorAffiliation::All],
+  ]
+);
+
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct CollaboratorsQuery {
+  pub affiliation: Option<CollaboratorAffiliation>,
+  pub permission: Option<Permission>
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct RepoCollaborator {
+  pub login: UserName,
+  pub id: u32,
+  pub avatar_url: Url,
+  pub html_url: Url,
+  pub site_admin: bool,
+  pub permissions: RepoPermissions,
+  pub role_name: Option<String>
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct CollaboratorPermission {
+  pub permission: PermissionLevel,
+  pub role_name: Option<String>,
+  pub user: GithubUser
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct AddCollaborator {
+  pub permission: Option<Permission>
+}
+
+optional_fields_encode!(
+  AddCollaborator [
+    permission,
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum CollaboratorStatus {
+  Collaborator,
+  NotCollaborator
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum AddedCollaboratorStatus {
+  Invited,
+  AlreadyCollaborator
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct RepoSummary {
+  pub id: u32,
+  pub owner: GithubUser,
+  pub name: RepoName,
+  pub full_name: RepoName,
+  pub private: bool,
+  pub html_url: Url,
+  pub url: Url
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct RepoInvitation {
+  pub id: InvitationId,
+  pub repository: RepoSummary,
+  pub invitee: Option<GithubUser>,
+  pub inviter: Option<GithubUser>,
+  pub permissions: PermissionLevel,
+  pub created_at: GitTm,
+  pub expired: Option<bool>,
+  pub url: Url,
+  pub html_url: Url
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum AcceptedStatus {
+  Accepted,
+  NotAccepted
+}
+
+pub type Topic = String;
+
+#[derive(RustcEncodable, RustcDecodable, Debug, PartialEq, Clone)]
+pub struct Topics {
+  pub names: Vec<Topic>
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ArchiveFormat {
+  Tarball,
+  Zipball
+}
@@ -0,0 +1,538 @@
+use rustc_serialize::{
+  Decodable,
+  Decoder,
+  Encodable,
+  Encoder,
+};
+
+use types::{
+  Url,
+  Sha,
+  GitTm,
+  Message,
+  Filename,
+  IssueId,
+  SortDirection,
+  GitErr,
+};
+
+use types::commits::GithubCommitSummary;
+use types::repos::{
+  Repository,
+  RepoSummary,
+};
+use types::users::{
+  UserName,
+  GithubUser,
+};
+
+pub type LabelId = u64;
+pub type RepoId = u32;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum IssueState {
+  Open,
+  Closed,
+}
+
+custom_enum_decode_encode!(
+  IssueState [
+    "open" <=> [IssueState::Open],
+    "closed" <=> [IssueState::Closed],
+  ]
+);
+
+/// A qualifier value compared against a date or number, e.g. `>=2020-01-01`
+#[derive(Debug, PartialEq, Clone)]
+pub enum SearchRange {
+  Exactly(String),
+  GreaterThan(String),
+  AtLeast(String),
+  LessThan(String),
+  AtMost(String),
+  Between(String, String),
+}
+
+impl SearchRange {
+  fn to_value(&self) -> String {
+    match *self {
+      SearchRange::Exactly(ref value) => value.clone(),
+      SearchRange::GreaterThan(ref value) => ">".to_owned() + value,
+      SearchRange::AtLeast(ref value) => ">=".to_owned() + value,
+      SearchRange::LessThan(ref value) => "<".to_owned() + value,
+      SearchRange::AtMost(ref value) => "<=".to_owned() + value,
+      SearchRange::Between(ref from, ref to) => from.clone() + ".." + to
+    }
+  }
+}
+
+// Quotes values that would otherwise be split into several terms, read as
+// a qualifier or read as an exclusion
+fn quote(value: &str) -> String {
+  let needs_quotes =
+    value.is_empty() ||
+    value.starts_with('-') ||
+    value.chars().any(|c| c.is_whitespace() || c == ':');
+  if needs_quotes {
+    "\"".to_owned() + value + "\""
+  } else {
+    value.to_owned()
+  }
+}
+
+fn is_qualifier_name(name: &str) -> bool {
+  !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Builds the `q` parameter of a search from keywords and qualifiers,
+/// quoting any value that contains whitespace or a colon. Github has no
+/// escape for double quotes, so a value containing one, like an invalid
+/// qualifier name, makes `build` fail.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SearchTerms {
+  terms: Vec<String>,
+  invalid: Option<String>
+}
+
+impl SearchTerms {
+  pub fn new() -> SearchTerms {
+    SearchTerms { terms: Vec::new(), invalid: None }
+  }
+
+  // Keeps the first invalid term, reported by build
+  fn push(mut self, term: Result<String, String>) -> SearchTerms {
+    match term {
+      Ok(term) => self.terms.push(term),
+      Err(invalid) => if self.invalid.is_none() { self.invalid = Some(invalid) }
+    }
+    self
+  }
+
+  fn value(value: &str) -> Result<String, String> {
+    if value.contains('"') {
+      Err("search terms cannot contain a double quote: ".to_owned() + value)
+    } else {
+      Ok(quote(value))
+    }
+  }
+
+  fn name(name: &str) -> Result<String, String> {
+    if is_qualifier_name(name) {
+      Ok(name.to_owned())
+    } else {
+      Err("invalid search qualifier: ".to_owned() + name)
+    }
+  }
+
+  pub fn keyword(self, keyword: &str) -> SearchTerms {
+    self.push(SearchTerms::value(keyword))
+  }
+
+  pub fn qualifier(self, name: &str, value: &str) -> SearchTerms {
+    let term = SearchTerms::name(name).and_then(|name| {
+      SearchTerms::value(value).map(|value| name + ":" + &value)
+    });
+    self.push(term)
+  }
+
+  pub fn excluding(self, name: &str, value: &str) -> SearchTerms {
+    let term = SearchTerms::name(name).and_then(|name| {
+      SearchTerms::value(value).map(|value| "-".to_owned() + &name + ":" + &value)
+    });
+    self.push(term)
+  }
+
+  // Range values are dates or numbers, which Github only reads unquoted
+  pub fn range(self, name: &str, range: SearchRange) -> SearchTerms {
+    let value = range.to_value();
+    let term = SearchTerms::name(name).and_then(|name| {
+      if value.chars().any(|c| c.is_whitespace() || c == '"') {
+        Err("invalid search range: ".to_owned() + &value)
+      } else {
+        Ok(name + ":" + &value)
+      }
+    });
+    self.push(term)
+  }
+
+  pub fn repo(self, repo: &Repository) -> SearchTerms {
+    let full_name = repo.owner.clone() + "/" + &repo.repo_name;
+    self.qualifier("repo", &full_name)
+  }
+
+  pub fn user(self, user: &UserName) -> SearchTerms {
+    self.qualifier("user", user)
+  }
+
+  pub fn org(self, org: &str) -> SearchTerms {
+    self.qualifier("org", org)
+  }
+
+  pub fn author(self, author: &UserName) -> SearchTerms {
+    self.qualifier("author", author)
+  }
+
+  pub fn assignee(self, assignee: &UserName) -> SearchTerms {
+    self.qualifier("assignee", assignee)
+  }
+
+  pub fn label(self, label: &str) -> SearchTerms {
+    self.qualifier("label", label)
+  }
+
+  pub fn language(self, language: &str) -> SearchTerms {
+    self.qualifier("language", language)
+  }
+
+  pub fn path(self, path: &str) -> SearchTerms {
+    self.qualifier("path", path)
+  }
+
+  pub fn is_pr(self) -> SearchTerms {
+    self.qualifier("is", "pr")
+  }
+
+  pub fn is_issue(self) -> SearchTerms {
+    self.qualifier("is", "issue")
+  }
+
+  pub fn state(self, state: IssueState) -> SearchTerms {
+    match state {
+      IssueState::Open => self.qualifier("state", "open"),
+      IssueState::Closed => self.qualifier("state", "closed")
+    }
+  }
+
+  pub fn created(self, range: SearchRange) -> SearchTerms {
+    self.range("created", range)
+  }
+
+  pub fn updated(self, range: SearchRange) -> SearchTerms {
+    self.range("updated", range)
+  }
+
+  pub fn closed(self, range: SearchRange) -> SearchTerms {
+    self.range("closed", range)
+  }
+
+  pub fn pushed(self, range: SearchRange) -> SearchTerms {
+    self.range("pushed", range)
+  }
+
+  pub fn stars(self, range: SearchRange) -> SearchTerms {
+    self.range("stars", range)
+  }
+
+  pub fn build(&self) -> Result<String, GitErr> {
+    match self.invalid {
+      Some(ref invalid) => Err(GitErr::EncodeErr(invalid.clone())),
+      None => Ok(self.terms.join(" "))
+    }
+  }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum RepoSearchSort {
+  Stars,
+  Forks,
+  HelpWantedIssues,
+  Updated,
+}
+
+custom_enum_decode_encode!(
+  RepoSearchSort [
+    "stars" <=> [RepoSearchSort::Stars],
+    "forks" <=> [RepoSearchSort::Forks],
+    "help-wanted-issues" <=> [RepoSearchSort::HelpWantedIssues],
+    "updated" <=> [RepoSearchSort::Updated],
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum CodeSearchSort {
+  Indexed,
+}
+
+custom_enum_decode_encode!(
+  CodeSearchSort [
+    "indexed" <=> [CodeSearchSort::Indexed],
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum IssueSearchSort {
+  Comments,
+  Reactions,
+  Interactions,
+  Created,
+  Updated,
+}
+
+custom_enum_decode_encode!(
+  IssueSearchSort [
+    "comments" <=> [IssueSearchSort::Comments],
+    "reactions" <=> [IssueSearchSort::Reactions],
+    "interactions" <=> [IssueSearchSort::Interactions],
+    "created" <=> [IssueSearchSort::Created],
+    "updated" <=> [IssueSearchSort::Updated],
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum UserSearchSort {
+  Followers,
+  Repositories,
+  Joined,
+}
+
+custom_enum_decode_encode!(
+  UserSearchSort [
+    "followers" <=> [UserSearchSort::Followers],
+    "repositories" <=> [UserSearchSort::Repositories],
+    "joined" <=> [UserSearchSort::Joined],
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum CommitSearchSort {
+  AuthorDate,
+  CommitterDate,
+}
+
+custom_enum_decode_encode!(
+  CommitSearchSort [
+    "author-date" <=> [CommitSearchSort::AuthorDate],
+    "committer-date" <=> [CommitSearchSort::CommitterDate],
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum LabelSearchSort {
+  Created,
+  Updated,
+}
+
+custom_enum_decode_encode!(
+  LabelSearchSort [
+    "created" <=> [LabelSearchSort::Created],
+    "updated" <=> [LabelSearchSort::Updated],
+  ]
+);
+
+/// `q` is usually built with SearchTerms
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct SearchQuery<S: Encodable> {
+  pub q: String,
+  pub sort: Option<S>,
+  pub order: Option<SortDirection>,
+  pub per_page: Option<u32>,
+  pub page: Option<u32>
+}
+
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct LabelSearchQuery {
+  pub repository_id: RepoId,
+  pub q: String,
+  pub sort: Option<LabelSearchSort>,
+  pub order: Option<SortDirection>,
+  pub per_page: Option<u32>,
+  pub page: Option<u32>
+}
+
+/// Github stops searching after a time limit, in which case
+/// `incomplete_results` is set and `items` may be missing matches
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct SearchResults<T: Decodable> {
+  pub total_count: u32,
+  pub incomplete_results: bool,
+  pub items: Vec<T>
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct CodeSearchItem {
+  pub name: Filename,
+  pub path: Filename,
+  pub sha: Sha,
+  pub url: Url,
+  pub git_url: Url,
+  pub html_url: Url,
+  pub repository: RepoSummary,
+  pub score: f64
+}
+
+#[derive(RustcDecodable, Debug, PartialEq, Clone)]
+pub struct Label {
+  pub id: LabelId,
+  pub url: Url,
+  pub name: String,
+  pub color: String,
+  pub default: bool,
+  pub description: Option<String>
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct IssuePullRequestLinks {
+  pub url: Url,
+  pub html_url: Url
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct IssueSearchItem {
+  pub id: IssueId,
+  pub url: Url,
+  pub html_url: Url,
+  pub repository_url: Url,
+  pub number: u32,
+  pub state: IssueState,
+  pub title: Message,
+  pub body: Option<Message>,
+  pub user: GithubUser,
+  pub labels: Vec<Label>,
+  pub assignees: Vec<GithubUser>,
+  pub comments: u32,
+  pub created_at: GitTm,
+  pub updated_at: GitTm,
+  pub closed_at: Option<GitTm>,
+  pub pull_request: Option<IssuePullRequestLinks>,
+  pub score: f64
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct CommitSearchItem {
+  pub url: Url,
+  pub sha: Sha,
+  pub html_url: Url,
+  pub commit: GithubCommitSummary,
+  pub author: Option<GithubUser>,
+  pub committer: Option<GithubUser>,
+  pub repository: RepoSummary,
+  pub score: f64
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct LabelSearchItem {
+  pub id: LabelId,
+  pub url: Url,
+  pub name: String,
+  pub color: String,
+  pub default: bool,
+  pub description: Option<String>,
+  pub score: f64
+}
+
+#[derive(RustcDecodable, Debug, PartialEq, Clone)]
+pub struct RateLimitResource {
+  pub limit: u32,
+  pub remaining: u32,
+  /// Seconds since the unix epoch
+  pub reset: i64
+}
+
+#[derive(RustcDecodable, Debug, PartialEq, Clone)]
+pub struct RateLimitResources {
+  pub core: RateLimitResource,
+  pub search: RateLimitResource
+}
+
+#[derive(RustcDecodable, Debug, PartialEq, Clone)]
+pub struct RateLimit {
+  pub resources: RateLimitResources
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+
+  use types::GitErr;
+  use types::repos::Repository;
+
+  use super::{
+    IssueState,
+    SearchRange,
+    SearchTerms,
+  };
+
+  #[test]
+  fn it_builds_plain_keywords() {
+    let terms = SearchTerms::new().keyword("tetris").keyword("rust");
+    expect!(terms.build()).to(be_equal_to(Ok("tetris rust".to_owned())));
+  }
+
+  #[test]
+  fn it_quotes_keywords_that_would_change_meaning() {
+    let cases = vec![
+      ("two words", r#""two words""#),
+      ("", r#""""#),
+      ("label:bug", r#""label:bug""#),
+      ("-draft", r#""-draft""#),
+      ("un-draft", "un-draft"),
+    ];
+
+    for (keyword, expected) in cases {
+      expect!(SearchTerms::new().keyword(keyword).build()).to(be_equal_to(Ok(expected.to_owned())));
+    }
+  }
+
+  #[test]
+  fn it_quotes_qualifier_values() {
+    let terms = SearchTerms::new().label("help wanted").language("rust");
+    expect!(terms.build()).to(be_equal_to(Ok(r#"label:"help wanted" language:rust"#.to_owned())));
+  }
+
+  #[test]
+  fn it_builds_exclusions() {
+    let terms = SearchTerms::new().keyword("crash").excluding("label", "wontfix").excluding("label", "needs info");
+    expect!(terms.build()).to(be_equal_to(Ok(r#"crash -label:wontfix -label:"needs info""#.to_owned())));
+  }
+
+  #[test]
+  fn it_builds_each_range() {
+    let cases = vec![
+      (SearchRange::Exactly("10".to_owned()), "stars:10"),
+      (SearchRange::GreaterThan("10".to_owned()), "stars:>10"),
+      (SearchRange::AtLeast("10".to_owned()), "stars:>=10"),
+      (SearchRange::LessThan("10".to_owned()), "stars:<10"),
+      (SearchRange::AtMost("10".to_owned()), "stars:<=10"),
+      (SearchRange::Between("10".to_owned(), "50".to_owned()), "stars:10..50"),
+    ];
+
+    for (range, expected) in cases {
+      expect!(SearchTerms::new().stars(range).build()).to(be_equal_to(Ok(expected.to_owned())));
+    }
+  }
+
+  #[test]
+  fn it_leaves_date_ranges_unquoted() {
+    let terms = SearchTerms::new().created(SearchRange::AtLeast("2020-01-01T10:00:00+07:00".to_owned()));
+    expect!(terms.build()).to(be_equal_to(Ok("created:>=2020-01-01T10:00:00+07:00".to_owned())));
+  }
+
+  #[test]
+  fn it_builds_issue_searches() {
+    let repo = Repository { owner: "test_owner".to_owned(), repo_name: "test_repo".to_owned() };
+    let terms = SearchTerms::new().keyword("panic").repo(&repo).is_issue().state(IssueState::Open);
+    expect!(terms.build()).to(be_equal_to(Ok("panic repo:test_owner/test_repo is:issue state:open".to_owned())));
+  }
+
+  #[test]
+  fn it_rejects_double_quotes() {
+    let terms = SearchTerms::new().keyword("fine").keyword(r#"say "hi""#);
+    expect!(terms.build()).to(be_equal_to(Err(GitErr::EncodeErr(r#"search terms cannot contain a double quote: say "hi""#.to_owned()))));
+  }
+
+  #[test]
+  fn it_rejects_invalid_qualifier_names() {
+    let cases = vec!["", "label bug", "repo:x", "-label"];
+
+    for name in cases {
+      expect!(SearchTerms::new().qualifier(name, "x").build().is_err()).to(be_equal_to(true));
+    }
+  }
+
+  #[test]
+  fn it_reports_the_first_invalid_term() {
+    let terms = SearchTerms::new().qualifier("bad name", "x").keyword("\"");
+    expect!(terms.build()).to(be_equal_to(Err(GitErr::EncodeErr("invalid search qualifier: bad name".to_owned()))));
+  }
+}
@@ -0,0 +1,143 @@
+use rustc_serialize::{
+  Decodable,
+  Decoder,
+};
+
+use types::users::GithubUser;
+
+/// Start of a week, in seconds since the unix epoch
+pub type WeekTimestamp = i64;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ContributorWeek {
+  pub week: WeekTimestamp,
+  pub additions: u32,
+  pub deletions: u32,
+  pub commits: u32
+}
+
+// Custom decode for the single letter field names
+impl Decodable for ContributorWeek {
+  fn decode<D: Decoder>(d: &mut D) -> Result<ContributorWeek, D::Error> {
+    d.read_struct("ContributorWeek", 4, |d| {
+      Ok(ContributorWeek {
+        week: try!(d.read_struct_field("w", 0, Decodable::decode)),
+        additions: try!(d.read_struct_field("a", 1, Decodable::decode)),
+        deletions: try!(d.read_struct_field("d", 2, Decodable::decode)),
+        commits: try!(d.read_struct_field("c", 3, Decodable::decode)),
+      })
+    })
+  }
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct ContributorStats {
+  pub author: Option<GithubUser>,
+  pub total: u32,
+  pub weeks: Vec<ContributorWeek>
+}
+
+/// Commits per day of the week, starting on Sunday
+#[derive(RustcDecodable, Debug, PartialEq, Clone)]
+pub struct CommitActivity {
+  pub days: Vec<u32>,
+  pub total: u32,
+  pub week: WeekTimestamp
+}
+
+/// Deletions are reported by Github as negative numbers
+#[derive(Debug, PartialEq, Clone)]
+pub struct CodeFrequency {
+  pub week: WeekTimestamp,
+  pub additions: i64,
+  pub deletions: i64
+}
+
+// Decoded from a [week, additions, deletions] array
+impl Decodable for CodeFrequency {
+  fn decode<D: Decoder>(d: &mut D) -> Result<CodeFrequency, D::Error> {
+    d.read_seq(|d, len| {
+      if len != 3 {
+        return Err(d.error("expected [week, additions, deletions]"));
+      }
+      Ok(CodeFrequency {
+        week: try!(d.read_seq_elt(0, Decodable::decode)),
+        additions: try!(d.read_seq_elt(1, Decodable::decode)),
+        deletions: try!(d.read_seq_elt(2, Decodable::decode)),
+      })
+    })
+  }
+}
+
+/// Weekly commit counts for the last 52 weeks, oldest first
+#[derive(RustcDecodable, Debug, PartialEq, Clone, Default)]
+pub struct Participation {
+  pub all: Vec<u32>,
+  pub owner: Vec<u32>
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct PunchCardHour {
+  pub day: u8,
+  pub hour: u8,
+  pub commits: u32
+}
+
+// Decoded from a [day, hour, commits] array
+impl Decodable for PunchCardHour {
+  fn decode<D: Decoder>(d: &mut D) -> Result<PunchCardHour, D::Error> {
+    d.read_seq(|d, len| {
+      if len != 3 {
+        return Err(d.error("expected [day, hour, commits]"));
+      }
+      Ok(PunchCardHour {
+        day: try!(d.read_seq_elt(0, Decodable::decode)),
+        hour: try!(d.read_seq_elt(1, Decodable::decode)),
+        commits: try!(d.read_seq_elt(2, Decodable::decode)),
+      })
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+  use rustc_serialize::json;
+
+  use super::{
+    CodeFrequency,
+    ContributorWeek,
+    PunchCardHour,
+  };
+
+  #[test]
+  fn it_decodes_contributor_weeks() {
+    let week: ContributorWeek = json::decode(r#"{"w": 1367712000, "a": 6898, "d": 77, "c": 10}"#).unwrap();
+    expect!(week).to(be_equal_to(ContributorWeek { week: 1367712000, additions: 6898, deletions: 77, commits: 10 }));
+  }
+
+  #[test]
+  fn it_decodes_code_frequency() {
+    let frequency: CodeFrequency = json::decode("[1302998400, 1124, -435]").unwrap();
+    expect!(frequency).to(be_equal_to(CodeFrequency { week: 1302998400, additions: 1124, deletions: -435 }));
+  }
+
+  #[test]
+  fn it_rejects_code_frequency_of_the_wrong_length() {
+    let frequency: Result<CodeFrequency, _> = json::decode("[1302998400, 1124]");
+    expect!(frequency.is_err()).to(be_equal_to(true));
+  }
+
+  #[test]
+  fn it_decodes_punch_card_hours() {
+    let hour: PunchCardHour = json::decode("[6, 23, 4]").unwrap();
+    expect!(hour).to(be_equal_to(PunchCardHour { day: 6, hour: 23, commits: 4 }));
+  }
+
+  #[test]
+  fn it_rejects_punch_card_hours_of_the_wrong_length() {
+    let hour: Result<PunchCardHour, _> = json::decode("[6, 23, 4, 1]");
+    expect!(hour.is_err()).to(be_equal_to(true));
+  }
+}
@@ -0,0 +1,100 @@
+use rustc_serialize::{
+  Decodable,
+  Decoder,
+  Encodable,
+  Encoder,
+};
+
+use types::{
+  Url,
+  Sha,
+  GitTm,
+};
+
+use types::users::GithubUser;
+
+pub type StatusId = u64;
+pub type StatusContext = String;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum StatusState {
+  Error,
+  Failure,
+  Pending,
+  Success,
+}
+
+custom_enum_decode_encode!(
+  StatusState [
+    "error" <=> [StatusState::Error],
+    "failure" <=> [StatusState::Failure],
+    "pending" <=> [StatusState::Pending],
+    "success" <=> [StatusState::Success],
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CreateStatus {
+  pub state: StatusState,
+  pub target_url: Option<Url>,
+  pub description: Option<String>,
+  pub context: Option<StatusContext>
+}
+
+// An unset context is left out, so Github files the status under "default"
+optional_fields_encode!(
+  CreateStatus {
+    "state" => state,
+  } [
+    target_url,
+    description,
+    context,
+  ]
+);
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct CommitStatus {
+  pub id: StatusId,
+  pub url: Url,
+  pub state: StatusState,
+  pub description: Option<String>,
+  pub target_url: Option<Url>,
+  pub context: StatusContext,
+  pub created_at: GitTm,
+  pub updated_at: GitTm,
+  pub creator: Option<GithubUser>
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct CombinedStatus {
+  pub state: StatusState,
+  pub sha: Sha,
+  pub total_count: u32,
+  pub statuses: Vec<CommitStatus>,
+  pub commit_url: Url,
+  pub url: Url
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+  use rustc_serialize::json;
+
+  use super::{
+    CreateStatus,
+    StatusState,
+  };
+
+  #[test]
+  fn it_leaves_unset_status_fields_out_of_the_body() {
+    let status = CreateStatus { state: StatusState::Pending, target_url: None, description: None, context: None };
+    expect!(json::encode(&status).unwrap()).to(be_equal_to(r#"{"state":"pending"}"#.to_owned()));
+  }
+
+  #[test]
+  fn it_sends_a_set_context() {
+    let status = CreateStatus { state: StatusState::Success, target_url: None, description: None, context: Some("ci/build".to_owned()) };
+    expect!(json::encode(&status).unwrap()).to(be_equal_to(r#"{"state":"success","context":"ci/build"}"#.to_owned()));
+  }
+}
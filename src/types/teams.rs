@@ -0,0 +1,213 @@
+use rustc_serialize::{
+  Decodable,
+  Decoder,
+  Encodable,
+  Encoder,
+};
+
+use types::{
+  Url,
+  GitTm,
+  Team,
+};
+
+use types::orgs::{
+  OrgSummary,
+  MembershipState,
+};
+
+use types::repos::{
+  Permission,
+  RepoName,
+};
+
+use types::users::UserName;
+
+pub type TeamId = u32;
+pub type TeamSlug = String;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum TeamPrivacy {
+  Secret,
+  Closed,
+}
+
+custom_enum_decode_encode!(
+  TeamPrivacy [
+    "secret" <=> [TeamPrivacy::Secret],
+    "closed" <=> [TeamPrivacy::Closed],
+  ]
+);
+
+/// A team as listed inside other resources, such as branch restrictions
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct TeamSummary {
+  pub id: TeamId,
+  pub url: Url,
+  pub html_url: Option<Url>,
+  pub name: String,
+  pub slug: TeamSlug,
+  pub description: Option<String>
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct FullTeam {
+  pub id: TeamId,
+  pub url: Url,
+  pub html_url: Url,
+  pub name: String,
+  pub slug: TeamSlug,
+  pub description: Option<String>,
+  pub privacy: TeamPrivacy,
+  pub permission: Permission,
+  pub members_url: Url,
+  pub repositories_url: Url,
+  pub parent: Option<Team>,
+  pub members_count: u32,
+  pub repos_count: u32,
+  pub created_at: GitTm,
+  pub updated_at: GitTm,
+  pub organization: OrgSummary
+}
+
+/// Nested teams must be `Closed`
+#[derive(Debug, PartialEq, Clone)]
+pub struct CreateTeam {
+  pub name: String,
+  pub description: Option<String>,
+  pub maintainers: Option<Vec<UserName>>,
+  pub repo_names: Option<Vec<RepoName>>,
+  pub privacy: Option<TeamPrivacy>,
+  pub parent_team_id: Option<TeamId>
+}
+
+optional_fields_encode!(
+  CreateTeam {
+    "name" => name,
+  } [
+    description,
+    maintainers,
+    repo_names,
+    privacy,
+    parent_team_id,
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct EditTeam {
+  pub name: Option<String>,
+  pub description: Option<String>,
+  pub privacy: Option<TeamPrivacy>,
+  pub parent_team_id: Option<TeamId>
+}
+
+optional_fields_encode!(
+  EditTeam [
+    name,
+    description,
+    privacy,
+    parent_team_id,
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum TeamRole {
+  Member,
+  Maintainer,
+}
+
+custom_enum_decode_encode!(
+  TeamRole [
+    "member" <=> [TeamRole::Member],
+    "maintainer" <=> [TeamRole::Maintainer],
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum TeamRoleFilter {
+  Member,
+  Maintainer,
+  All,
+}
+
+custom_enum_decode_encode!(
+  TeamRoleFilter [
+    "member" <=> [TeamRoleFilter::Member],
+    "maintainer" <=> [TeamRoleFilter::Maintainer],
+    "all" <=> [TeamRoleFilter::All],
+  ]
+);
+
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct TeamMembersQuery {
+  pub role: Option<TeamRoleFilter>,
+  pub per_page: Option<u32>,
+  pub page: Option<u32>
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct TeamMembership {
+  pub url: Url,
+  pub role: TeamRole,
+  pub state: MembershipState
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct SetTeamMembership {
+  pub role: Option<TeamRole>
+}
+
+optional_fields_encode!(
+  SetTeamMembership [
+    role,
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct SetTeamRepoPermission {
+  pub permission: Option<Permission>
+}
+
+optional_fields_encode!(
+  SetTeamRepoPermission [
+    permission,
+  ]
+);
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum TeamRepoStatus {
+  Managed,
+  NotManaged
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+  use rustc_serialize::json;
+
+  use super::{
+    CreateTeam,
+    EditTeam,
+    SetTeamMembership,
+    TeamPrivacy,
+  };
+
+  #[test]
+  fn it_leaves_unset_fields_out_of_a_new_team() {
+    let create = CreateTeam { name: "Justice League".to_owned(), description: None, maintainers: None, repo_names: None, privacy: None, parent_team_id: None };
+    expect!(json::encode(&create).unwrap()).to(be_equal_to(r#"{"name":"Justice League"}"#.to_owned()));
+  }
+
+  #[test]
+  fn it_leaves_the_parent_and_description_alone_when_unset() {
+    let edit = EditTeam { name: None, description: None, privacy: Some(TeamPrivacy::Closed), parent_team_id: None };
+    expect!(json::encode(&edit).unwrap()).to(be_equal_to(r#"{"privacy":"closed"}"#.to_owned()));
+  }
+
+  #[test]
+  fn it_sends_an_empty_membership_without_a_role() {
+    let membership = SetTeamMembership { role: None };
+    expect!(json::encode(&membership).unwrap()).to(be_equal_to("{}".to_owned()));
+  }
+}
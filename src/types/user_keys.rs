@@ -0,0 +1,120 @@
+use rustc_serialize::{
+  Encodable,
+  Encoder,
+};
+
+use types::{
+  Url,
+  GitTm,
+};
+
+pub type SshKeyId = u64;
+pub type GpgKeyId = u64;
+
+/// A key as shown on a user's public profile
+#[derive(RustcDecodable, Debug, PartialEq, Clone)]
+pub struct PublicSshKey {
+  pub id: SshKeyId,
+  pub key: String
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct SshKey {
+  pub id: SshKeyId,
+  pub key: String,
+  pub url: Url,
+  pub title: String,
+  pub created_at: GitTm,
+  pub verified: Option<bool>,
+  pub read_only: Option<bool>
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CreateSshKey {
+  pub title: Option<String>,
+  pub key: String
+}
+
+optional_fields_encode!(
+  CreateSshKey {
+    "key" => key,
+  } [
+    title,
+  ]
+);
+
+#[derive(RustcDecodable, Debug, PartialEq, Clone)]
+pub struct GpgKeyEmail {
+  pub email: String,
+  pub verified: bool
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct GpgSubkey {
+  pub id: GpgKeyId,
+  pub primary_key_id: Option<GpgKeyId>,
+  pub key_id: String,
+  pub public_key: String,
+  pub can_sign: bool,
+  pub can_encrypt_comms: bool,
+  pub can_encrypt_storage: bool,
+  pub can_certify: bool,
+  pub created_at: GitTm,
+  pub expires_at: Option<GitTm>
+}
+
+#[derive(RustcDecodable, Debug, Clone)]
+pub struct GpgKey {
+  pub id: GpgKeyId,
+  pub name: Option<String>,
+  pub primary_key_id: Option<GpgKeyId>,
+  pub key_id: String,
+  pub public_key: String,
+  pub emails: Vec<GpgKeyEmail>,
+  pub subkeys: Vec<GpgSubkey>,
+  pub can_sign: bool,
+  pub can_encrypt_comms: bool,
+  pub can_encrypt_storage: bool,
+  pub can_certify: bool,
+  pub created_at: GitTm,
+  pub expires_at: Option<GitTm>,
+  pub raw_key: Option<String>
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CreateGpgKey {
+  pub name: Option<String>,
+  pub armored_public_key: String
+}
+
+optional_fields_encode!(
+  CreateGpgKey {
+    "armored_public_key" => armored_public_key,
+  } [
+    name,
+  ]
+);
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+  use rustc_serialize::json;
+
+  use super::{
+    CreateSshKey,
+    CreateGpgKey,
+  };
+
+  #[test]
+  fn it_leaves_an_unset_ssh_key_title_out_of_the_body() {
+    let key = CreateSshKey { title: None, key: "ssh-ed25519 AAAA".to_owned() };
+    expect!(json::encode(&key).unwrap()).to(be_equal_to(r#"{"key":"ssh-ed25519 AAAA"}"#.to_owned()));
+  }
+
+  #[test]
+  fn it_leaves_an_unset_gpg_key_name_out_of_the_body() {
+    let key = CreateGpgKey { name: None, armored_public_key: "-----BEGIN PGP PUBLIC KEY BLOCK-----".to_owned() };
+    expect!(json::encode(&key).unwrap()).to(be_equal_to(r#"{"armored_public_key":"-----BEGIN PGP PUBLIC KEY BLOCK-----"}"#.to_owned()));
+  }
+}
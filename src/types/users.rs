@@ -1,11 +1,22 @@
-use types::Url;
+use rustc_serialize::{
+  Decodable,
+  Decoder,
+  Encodable,
+  Encoder,
+};
+
+use types::{
+  Url,
+  GitTm,
+};
 
 pub type UserName = String;
+pub type UserId = u32;
 
 #[derive(RustcDecodable, Debug, Clone)]
 pub struct GithubUser {
   pub login: UserName,
-  pub id: u32,
+  pub id: UserId,
   pub avatar_url: Url,
   pub gravatar_id: String,  // TODO: What is this
   pub html_url: Url,
@@ -28,3 +39,243 @@ pub struct GitUser {
   pub email: String,
   pub username: Option<UserName>
 }
+
+#[derive(Debug, Clone)]
+pub struct FullUser {
+  pub login: UserName,
+  pub id: UserId,
+  pub avatar_url: Url,
+  pub url: Url,
+  pub html_url: Url,
+  pub followers_url: Url,
+  pub following_url: Url,
+  pub repos_url: Url,
+  pub site_admin: bool,
+  pub name: Option<String>,
+  pub company: Option<String>,
+  pub blog: Option<Url>,
+  pub location: Option<String>,
+  pub email: Option<String>,
+  pub hireable: Option<bool>,
+  pub bio: Option<String>,
+  pub twitter_username: Option<String>,
+  pub public_repos: u32,
+  pub public_gists: u32,
+  pub followers: u32,
+  pub following: u32,
+  pub created_at: GitTm,
+  pub updated_at: GitTm
+}
+
+// Reads the fields of a FullUser from within a struct, so that the
+// authenticated user can share them
+fn read_full_user<D: Decoder>(d: &mut D) -> Result<FullUser, D::Error> {
+  Ok(FullUser {
+    login: try!(d.read_struct_field("login", 0, Decodable::decode)),
+    id: try!(d.read_struct_field("id", 1, Decodable::decode)),
+    avatar_url: try!(d.read_struct_field("avatar_url", 2, Decodable::decode)),
+    url: try!(d.read_struct_field("url", 3, Decodable::decode)),
+    html_url: try!(d.read_struct_field("html_url", 4, Decodable::decode)),
+    followers_url: try!(d.read_struct_field("followers_url", 5, Decodable::decode)),
+    following_url: try!(d.read_struct_field("following_url", 6, Decodable::decode)),
+    repos_url: try!(d.read_struct_field("repos_url", 7, Decodable::decode)),
+    site_admin: try!(d.read_struct_field("site_admin", 8, Decodable::decode)),
+    name: try!(d.read_struct_field("name", 9, Decodable::decode)),
+    company: try!(d.read_struct_field("company", 10, Decodable::decode)),
+    blog: try!(d.read_struct_field("blog", 11, Decodable::decode)),
+    location: try!(d.read_struct_field("location", 12, Decodable::decode)),
+    email: try!(d.read_struct_field("email", 13, Decodable::decode)),
+    hireable: try!(d.read_struct_field("hireable", 14, Decodable::decode)),
+    bio: try!(d.read_struct_field("bio", 15, Decodable::decode)),
+    twitter_username: try!(d.read_struct_field("twitter_username", 16, Decodable::decode)),
+    public_repos: try!(d.read_struct_field("public_repos", 17, Decodable::decode)),
+    public_gists: try!(d.read_struct_field("public_gists", 18, Decodable::decode)),
+    followers: try!(d.read_struct_field("followers", 19, Decodable::decode)),
+    following: try!(d.read_struct_field("following", 20, Decodable::decode)),
+    created_at: try!(d.read_struct_field("created_at", 21, Decodable::decode)),
+    updated_at: try!(d.read_struct_field("updated_at", 22, Decodable::decode)),
+  })
+}
+
+impl Decodable for FullUser {
+  fn decode<D: Decoder>(d: &mut D) -> Result<FullUser, D::Error> {
+    d.read_struct("FullUser", 23, read_full_user)
+  }
+}
+
+#[derive(RustcDecodable, Debug, PartialEq, Clone)]
+pub struct Plan {
+  pub name: String,
+  pub space: u64,
+  pub private_repos: u32,
+  pub collaborators: u32
+}
+
+/// The user the client is authenticated as, including the fields Github
+/// only shows to the user themselves
+#[derive(Debug, Clone)]
+pub struct AuthenticatedUser {
+  pub user: FullUser,
+  pub private_gists: Option<u32>,
+  pub total_private_repos: Option<u32>,
+  pub owned_private_repos: Option<u32>,
+  pub disk_usage: Option<u64>,
+  pub collaborators: Option<u32>,
+  pub two_factor_authentication: Option<bool>,
+  pub plan: Option<Plan>
+}
+
+// Custom decode, as the public fields sit alongside the private ones
+impl Decodable for AuthenticatedUser {
+  fn decode<D: Decoder>(d: &mut D) -> Result<AuthenticatedUser, D::Error> {
+    d.read_struct("AuthenticatedUser", 30, |d| {
+      Ok(AuthenticatedUser {
+        user: try!(read_full_user(d)),
+        private_gists: try!(d.read_struct_field("private_gists", 23, Decodable::decode)),
+        total_private_repos: try!(d.read_struct_field("total_private_repos", 24, Decodable::decode)),
+        owned_private_repos: try!(d.read_struct_field("owned_private_repos", 25, Decodable::decode)),
+        disk_usage: try!(d.read_struct_field("disk_usage", 26, Decodable::decode)),
+        collaborators: try!(d.read_struct_field("collaborators", 27, Decodable::decode)),
+        two_factor_authentication: try!(d.read_struct_field("two_factor_authentication", 28, Decodable::decode)),
+        plan: try!(d.read_struct_field("plan", 29, Decodable::decode)),
+      })
+    })
+  }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct UpdateUser {
+  pub name: Option<String>,
+  pub email: Option<String>,
+  pub blog: Option<Url>,
+  pub twitter_username: Option<String>,
+  pub company: Option<String>,
+  pub location: Option<String>,
+  pub hireable: Option<bool>,
+  pub bio: Option<String>
+}
+
+optional_fields_encode!(
+  UpdateUser [
+    name,
+    email,
+    blog,
+    twitter_username,
+    company,
+    location,
+    hireable,
+    bio,
+  ]
+);
+
+/// Users are listed in order of id; `since` is the last id already seen
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct UsersQuery {
+  pub since: Option<UserId>,
+  pub per_page: Option<u32>
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum HovercardSubject {
+  Organization,
+  Repository,
+  Issue,
+  PullRequest,
+}
+
+custom_enum_decode_encode!(
+  HovercardSubject [
+    "organization" <=> [HovercardSubject::Organization],
+    "repository" <=> [HovercardSubject::Repository],
+    "issue" <=> [HovercardSubject::Issue],
+    "pull_request" <=> [HovercardSubject::PullRequest],
+  ]
+);
+
+#[derive(RustcEncodable, Debug, PartialEq, Clone)]
+pub struct HovercardQuery {
+  pub subject_type: HovercardSubject,
+  pub subject_id: String
+}
+
+#[derive(RustcDecodable, Debug, PartialEq, Clone)]
+pub struct HovercardContext {
+  pub message: String,
+  pub octicon: String
+}
+
+#[derive(RustcDecodable, Debug, PartialEq, Clone)]
+pub struct Hovercard {
+  pub contexts: Vec<HovercardContext>
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum FollowingStatus {
+  Following,
+  NotFollowing
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum FollowedStatus {
+  Followed,
+  NotFollowed
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+  use rustc_serialize::json;
+
+  use super::{
+    AuthenticatedUser,
+    UpdateUser,
+  };
+
+  #[test]
+  fn it_encodes_only_the_fields_being_updated() {
+    let update = UpdateUser {
+      name: None,
+      email: None,
+      blog: None,
+      twitter_username: None,
+      company: None,
+      location: None,
+      hireable: None,
+      bio: Some("Writes Rust".to_owned())
+    };
+    expect!(json::encode(&update).unwrap()).to(be_equal_to(r#"{"bio":"Writes Rust"}"#.to_owned()));
+  }
+
+  #[test]
+  fn it_decodes_the_authenticated_user_alongside_its_public_profile() {
+    let user: AuthenticatedUser = json::decode(r#"{
+      "login": "octocat",
+      "id": 1,
+      "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+      "url": "https://api.github.com/users/octocat",
+      "html_url": "https://github.com/octocat",
+      "followers_url": "https://api.github.com/users/octocat/followers",
+      "following_url": "https://api.github.com/users/octocat/following{/other_user}",
+      "repos_url": "https://api.github.com/users/octocat/repos",
+      "site_admin": false,
+      "name": "monalisa octocat",
+      "bio": null,
+      "public_repos": 2,
+      "public_gists": 1,
+      "followers": 20,
+      "following": 0,
+      "created_at": "2008-01-14T04:33:35Z",
+      "updated_at": "2008-01-14T04:33:35Z",
+      "private_gists": 81,
+      "total_private_repos": 100,
+      "plan": { "name": "Medium", "space": 400, "private_repos": 20, "collaborators": 0 }
+    }"#).unwrap();
+
+    expect!(user.user.login).to(be_equal_to("octocat".to_owned()));
+    expect!(user.user.name).to(be_equal_to(Some("monalisa octocat".to_owned())));
+    expect!(user.private_gists).to(be_equal_to(Some(81)));
+    expect!(user.disk_usage).to(be_equal_to(None));
+    expect!(user.plan.map(|plan| plan.name)).to(be_equal_to(Some("Medium".to_owned())));
+  }
+}
@@ -0,0 +1,94 @@
+pub mod url_builders;
+
+use hyper::method::Method;
+
+use github_client::{
+  SimpleClient,
+  deleted_status,
+};
+
+use types::{
+  GitErr,
+  DeletedStatus,
+};
+
+use types::users::UserName;
+
+use types::user_keys::{
+  SshKeyId,
+  PublicSshKey,
+  SshKey,
+  CreateSshKey,
+  GpgKeyId,
+  GpgKey,
+  CreateGpgKey,
+};
+
+pub trait UserKeyer {
+  fn list_user_ssh_keys(&self, user: UserName) -> Result<Vec<PublicSshKey>, GitErr>;
+  fn list_own_ssh_keys(&self) -> Result<Vec<SshKey>, GitErr>;
+  fn get_own_ssh_key(&self, key_id: SshKeyId) -> Result<SshKey, GitErr>;
+  fn create_own_ssh_key(&self, details: CreateSshKey) -> Result<SshKey, GitErr>;
+  fn delete_own_ssh_key(&self, key_id: SshKeyId) -> Result<DeletedStatus, GitErr>;
+  fn list_user_gpg_keys(&self, user: UserName) -> Result<Vec<GpgKey>, GitErr>;
+  fn list_own_gpg_keys(&self) -> Result<Vec<GpgKey>, GitErr>;
+  fn get_own_gpg_key(&self, key_id: GpgKeyId) -> Result<GpgKey, GitErr>;
+  fn create_own_gpg_key(&self, details: CreateGpgKey) -> Result<GpgKey, GitErr>;
+  fn delete_own_gpg_key(&self, key_id: GpgKeyId) -> Result<DeletedStatus, GitErr>;
+}
+
+impl<C: SimpleClient> UserKeyer for C {
+  fn list_user_ssh_keys(&self, user: UserName) -> Result<Vec<PublicSshKey>, GitErr> {
+    let url = url_builders::user_ssh_keys(&user);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn list_own_ssh_keys(&self) -> Result<Vec<SshKey>, GitErr> {
+    let url = url_builders::own_ssh_keys();
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn get_own_ssh_key(&self, key_id: SshKeyId) -> Result<SshKey, GitErr> {
+    let url = url_builders::own_ssh_key_at(&key_id);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn create_own_ssh_key(&self, details: CreateSshKey) -> Result<SshKey, GitErr> {
+    let url = url_builders::own_ssh_keys();
+    self.request_with_payload(Method::Post, url, details)
+  }
+
+  fn delete_own_ssh_key(&self, key_id: SshKeyId) -> Result<DeletedStatus, GitErr> {
+    let url = url_builders::own_ssh_key_at(&key_id);
+    self
+      .request_for_status(Method::Delete, url)
+      .and_then(deleted_status)
+  }
+
+  fn list_user_gpg_keys(&self, user: UserName) -> Result<Vec<GpgKey>, GitErr> {
+    let url = url_builders::user_gpg_keys(&user);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn list_own_gpg_keys(&self) -> Result<Vec<GpgKey>, GitErr> {
+    let url = url_builders::own_gpg_keys();
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn get_own_gpg_key(&self, key_id: GpgKeyId) -> Result<GpgKey, GitErr> {
+    let url = url_builders::own_gpg_key_at(&key_id);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn create_own_gpg_key(&self, details: CreateGpgKey) -> Result<GpgKey, GitErr> {
+    let url = url_builders::own_gpg_keys();
+    self.request_with_payload(Method::Post, url, details)
+  }
+
+  fn delete_own_gpg_key(&self, key_id: GpgKeyId) -> Result<DeletedStatus, GitErr> {
+    let url = url_builders::own_gpg_key_at(&key_id);
+    self
+      .request_for_status(Method::Delete, url)
+      .and_then(deleted_status)
+  }
+}
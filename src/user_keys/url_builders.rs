@@ -0,0 +1,86 @@
+use types::Url;
+use types::users::UserName;
+use types::user_keys::{
+  SshKeyId,
+  GpgKeyId,
+};
+
+use users::url_builders::{
+  authenticated_user,
+  user_at,
+};
+
+pub fn own_ssh_keys() -> Url {
+  authenticated_user() + "/keys"
+}
+
+pub fn own_ssh_key_at(key_id: &SshKeyId) -> Url {
+  own_ssh_keys() + "/" + &key_id.to_string()
+}
+
+pub fn user_ssh_keys(user: &UserName) -> Url {
+  user_at(user) + "/keys"
+}
+
+pub fn own_gpg_keys() -> Url {
+  authenticated_user() + "/gpg_keys"
+}
+
+pub fn own_gpg_key_at(key_id: &GpgKeyId) -> Url {
+  own_gpg_keys() + "/" + &key_id.to_string()
+}
+
+pub fn user_gpg_keys(user: &UserName) -> Url {
+  user_at(user) + "/gpg_keys"
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+
+  use super::{
+    own_ssh_keys,
+    own_ssh_key_at,
+    user_ssh_keys,
+    own_gpg_keys,
+    own_gpg_key_at,
+    user_gpg_keys,
+  };
+
+  #[test]
+  fn it_builds_own_ssh_keys() {
+    let expected = "https://api.github.com/user/keys";
+    expect!(own_ssh_keys()).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_own_ssh_key_at() {
+    let expected = "https://api.github.com/user/keys/42";
+    expect!(own_ssh_key_at(&42)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_user_ssh_keys() {
+    let expected = "https://api.github.com/users/test_user/keys";
+    expect!(user_ssh_keys(&("test_user".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_own_gpg_keys() {
+    let expected = "https://api.github.com/user/gpg_keys";
+    expect!(own_gpg_keys()).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_own_gpg_key_at() {
+    let expected = "https://api.github.com/user/gpg_keys/42";
+    expect!(own_gpg_key_at(&42)).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_user_gpg_keys() {
+    let expected = "https://api.github.com/users/test_user/gpg_keys";
+    expect!(user_gpg_keys(&("test_user".to_owned()))).to(be_equal_to(expected));
+  }
+}
@@ -0,0 +1,59 @@
+pub mod url_builders;
+
+use hyper::method::Method;
+
+use github_client::{SimpleClient};
+
+use types::GitErr;
+
+use types::users::{
+  UserName,
+  GithubUser,
+  FullUser,
+  AuthenticatedUser,
+  UpdateUser,
+  UsersQuery,
+  HovercardQuery,
+  Hovercard,
+};
+
+pub trait Userer {
+  fn get_authenticated_user(&self) -> Result<AuthenticatedUser, GitErr>;
+  fn get_user(&self, user: UserName) -> Result<FullUser, GitErr>;
+  fn update_authenticated_user(&self, details: UpdateUser) -> Result<AuthenticatedUser, GitErr>;
+  fn list_users(&self, query: Option<UsersQuery>) -> Result<Vec<GithubUser>, GitErr>;
+  fn get_hovercard(&self, user: UserName, query: Option<HovercardQuery>) -> Result<Hovercard, GitErr>;
+}
+
+impl<C: SimpleClient> Userer for C {
+  fn get_authenticated_user(&self) -> Result<AuthenticatedUser, GitErr> {
+    let url = url_builders::authenticated_user();
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn get_user(&self, user: UserName) -> Result<FullUser, GitErr> {
+    let url = url_builders::user_at(&user);
+    self.request_without_payload(Method::Get, url)
+  }
+
+  fn update_authenticated_user(&self, details: UpdateUser) -> Result<AuthenticatedUser, GitErr> {
+    let url = url_builders::authenticated_user();
+    self.request_with_payload(Method::Patch, url, details)
+  }
+
+  fn list_users(&self, query: Option<UsersQuery>) -> Result<Vec<GithubUser>, GitErr> {
+    let url = url_builders::all_users();
+    match query {
+      Some(query) => self.request_with_query(Method::Get, url, query),
+      None => self.request_without_payload(Method::Get, url)
+    }
+  }
+
+  fn get_hovercard(&self, user: UserName, query: Option<HovercardQuery>) -> Result<Hovercard, GitErr> {
+    let url = url_builders::hovercard_at(&user);
+    match query {
+      Some(query) => self.request_with_query(Method::Get, url, query),
+      None => self.request_without_payload(Method::Get, url)
+    }
+  }
+}
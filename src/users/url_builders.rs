@@ -0,0 +1,55 @@
+use types::Url;
+use types::users::UserName;
+
+pub fn authenticated_user() -> Url {
+  "https://api.github.com/user".to_owned()
+}
+
+pub fn all_users() -> Url {
+  "https://api.github.com/users".to_owned()
+}
+
+pub fn user_at(user: &UserName) -> Url {
+  all_users() + "/" + user
+}
+
+pub fn hovercard_at(user: &UserName) -> Url {
+  user_at(user) + "/hovercard"
+}
+
+#[cfg(test)]
+mod tests {
+  use expectest::core::expect;
+  use expectest::matchers::be_equal_to;
+
+  use super::{
+    authenticated_user,
+    all_users,
+    user_at,
+    hovercard_at,
+  };
+
+  #[test]
+  fn it_builds_authenticated_user() {
+    let expected = "https://api.github.com/user";
+    expect!(authenticated_user()).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_all_users() {
+    let expected = "https://api.github.com/users";
+    expect!(all_users()).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_user_at() {
+    let expected = "https://api.github.com/users/test_user";
+    expect!(user_at(&("test_user".to_owned()))).to(be_equal_to(expected));
+  }
+
+  #[test]
+  fn it_builds_hovercard_at() {
+    let expected = "https://api.github.com/users/test_user/hovercard";
+    expect!(hovercard_at(&("test_user".to_owned()))).to(be_equal_to(expected));
+  }
+}